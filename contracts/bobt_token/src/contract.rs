@@ -2,26 +2,51 @@
 //!
 //! Professional-grade stablecoin on Stellar Soroban with:
 //! - SEP-41 compliant fungible token interface
-//! - Role-based access control (OWNER, MINTER, PAUSER, BLACKLISTER, RESCUER)
-//! - Pausable functionality for emergencies
+//! - Signed permit approvals and relayed transfers with a BOBT fee
+//! - Optional transfer fee with exemptions
+//! - All-or-nothing batch transfers
+//! - Transfers with payment memos
+//! - Role-based access control (OWNER, MINTER, MASTER_MINTER, PAUSER, BLACKLISTER,
+//!   RESCUER, KYC_OFFICER, HOLD_OPERATOR, ATTESTOR)
+//! - Per-minter mint allowances managed by the master minter
+//! - Pausable functionality for emergencies, globally or per operation class
 //! - Blacklist/freeze capability for compliance
-//! - Clawback (destroy frozen funds) capability
+//! - Balance holds (partial locks) managed by hold operators
+//! - Permissioned mode with per-holder KYC tiers and limits
+//! - Clawback (destroy frozen funds, partial seizure to custody) capability
 //! - Request tracking for mint/burn operations with full audit trail
+//! - Historical balance and supply checkpoints
+//! - Two-phase mints reserved until bank settlement
+//! - Mint reversal (chargeback) by request ID
+//! - Proof-of-reserves gate on minting from attested reserves
 //! - Rescue tokens functionality
-//! - Upgradeable contract with migration support
+//! - Timelocked upgrades with migration support
+
+// The generated contract client mirrors entrypoint signatures, so relayed
+// entrypoints that take a full signed payload exceed the argument lint.
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contractimpl, token::TokenClient, Address, BytesN, Env, String, Symbol,
+    contract, contractimpl, token::TokenClient, Address, Bytes, BytesN, Env, String, Symbol, Vec,
 };
 
 use crate::errors::BOBTError;
 use crate::events::*;
+use crate::migration;
+use crate::signature;
 use crate::storage::{
     self, extend_instance_ttl, freeze_account, get_allowance, get_balance, get_owner,
     get_total_supply, grant_role, has_role, is_frozen, is_initialized, is_paused, revoke_role,
-    set_allowance, set_balance, set_initialized, set_owner, set_paused, set_total_supply,
-    spend_allowance, unfreeze_account, BurnRequest, MintRequest, MAX_SUPPLY, MIN_TRANSFER,
-    ROLE_BLACKLISTER, ROLE_MINTER, ROLE_OWNER, ROLE_PAUSER, ROLE_RESCUER, TOKEN_DECIMALS,
+    set_allowance, set_balance, set_initialized, set_owner, set_total_supply, spend_allowance,
+    unfreeze_account, BurnRequest, ClawbackRecord, ContractConfig, FeeConfig, FreezeReason,
+    FreezeRecord, Hold, KycLevel, MintRequest, MintReservation, MintReversal, PendingOwner,
+    PendingUpgrade, RequestKind, RequestRef, ReserveAttestation, ReserveConfig, TierLimits,
+    BPS_DENOMINATOR, MAX_BACKFILL_BATCH, MAX_BATCH_SIZE, MAX_BUCKETS_PER_EXTEND, MAX_FEE_BPS,
+    MAX_HOLDS_PER_ACCOUNT, MAX_MEMO_LEN, MAX_PAGE_SIZE, MAX_REQUEST_BACKFILL_BATCH,
+    MAX_REQUEST_ID_LEN, MAX_ROLE_MEMBERS, MAX_SUPPLY, MAX_UPGRADE_DELAY, MIN_TRANSFER,
+    MIN_UPGRADE_DELAY, PAUSE_ALL, PAUSE_APPROVE, PAUSE_BURN, PAUSE_MINT, PAUSE_TRANSFER,
+    ROLE_ATTESTOR, ROLE_BLACKLISTER, ROLE_COMPLIANCE, ROLE_HOLD_OPERATOR, ROLE_KYC_OFFICER,
+    ROLE_MASTER_MINTER, ROLE_MINTER, ROLE_OWNER, ROLE_PAUSER, ROLE_RESCUER, TOKEN_DECIMALS,
     TOKEN_NAME, TOKEN_SYMBOL,
 };
 
@@ -37,7 +62,6 @@ pub struct BOBTToken;
 // =============================================================================
 
 /// Require the contract to be initialized
-#[allow(dead_code)]
 #[inline]
 fn require_initialized(env: &Env) -> Result<(), BOBTError> {
     if !is_initialized(env) {
@@ -46,20 +70,20 @@ fn require_initialized(env: &Env) -> Result<(), BOBTError> {
     Ok(())
 }
 
-/// Require the contract to not be paused
+/// Require an operation class (`PAUSE_*` flag) to not be paused
 #[inline]
-fn require_not_paused(env: &Env) -> Result<(), BOBTError> {
-    if is_paused(env) {
+fn require_not_paused(env: &Env, flag: u32) -> Result<(), BOBTError> {
+    if storage::get_pause_flags(env) & flag != 0 {
         return Err(BOBTError::ContractPaused);
     }
     Ok(())
 }
 
-/// Require the contract to be paused
+/// Validate a pause bitmask: non-empty and only known `PAUSE_*` flags
 #[inline]
-fn require_paused(env: &Env) -> Result<(), BOBTError> {
-    if !is_paused(env) {
-        return Err(BOBTError::ContractNotPaused);
+fn validate_pause_flags(flags: u32) -> Result<(), BOBTError> {
+    if flags == 0 || flags & !PAUSE_ALL != 0 {
+        return Err(BOBTError::InvalidConfig);
     }
     Ok(())
 }
@@ -73,6 +97,62 @@ fn require_not_frozen(env: &Env, account: &Address) -> Result<(), BOBTError> {
     Ok(())
 }
 
+/// Require the fee collector to be able to receive fees at `new_balance`
+///
+/// The collector must not be frozen and, in permissioned mode, must be
+/// KYC-approved within its tier's balance limit.
+fn require_fee_collector(
+    env: &Env,
+    collector: &Address,
+    new_balance: i128,
+) -> Result<(), BOBTError> {
+    require_not_frozen(env, collector)?;
+    if storage::get_config(env).require_whitelist {
+        require_kyc_receive(env, collector, new_balance)?;
+    }
+    Ok(())
+}
+
+/// Require `amount` to be covered by `balance` without touching funds on hold
+#[inline]
+fn require_spendable(
+    env: &Env,
+    account: &Address,
+    balance: i128,
+    amount: i128,
+) -> Result<(), BOBTError> {
+    if balance - storage::get_held_balance(env, account) < amount {
+        return Err(BOBTError::FundsOnHold);
+    }
+    Ok(())
+}
+
+/// Require account to be KYC-approved and stay within its tier's balance limit
+#[inline]
+fn require_kyc_receive(env: &Env, account: &Address, new_balance: i128) -> Result<(), BOBTError> {
+    let level = storage::get_kyc_level(env, account);
+    if level == KycLevel::None {
+        return Err(BOBTError::AccountNotApproved);
+    }
+    if new_balance > storage::get_tier_limits(env, level).max_balance {
+        return Err(BOBTError::KycLimitExceeded);
+    }
+    Ok(())
+}
+
+/// Require account to be KYC-approved and within its tier's per-transfer limit
+#[inline]
+fn require_kyc_send(env: &Env, account: &Address, amount: i128) -> Result<(), BOBTError> {
+    let level = storage::get_kyc_level(env, account);
+    if level == KycLevel::None {
+        return Err(BOBTError::AccountNotApproved);
+    }
+    if amount > storage::get_tier_limits(env, level).max_transfer {
+        return Err(BOBTError::KycLimitExceeded);
+    }
+    Ok(())
+}
+
 /// Require caller to have a specific role
 #[inline]
 fn require_role(env: &Env, role: &Symbol, caller: &Address) -> Result<(), BOBTError> {
@@ -82,14 +162,45 @@ fn require_role(env: &Env, role: &Symbol, caller: &Address) -> Result<(), BOBTEr
     Ok(())
 }
 
+/// Require caller to be the minter who reserved a mint, or a master minter
+#[inline]
+fn require_reservation_operator(
+    env: &Env,
+    caller: &Address,
+    reservation: &MintReservation,
+) -> Result<(), BOBTError> {
+    if *caller == reservation.minter {
+        require_role(env, &ROLE_MINTER, caller)
+    } else {
+        require_role(env, &ROLE_MASTER_MINTER, caller)
+    }
+}
+
+/// Require caller to be allowed to manage a role
+///
+/// The owner can manage every role. Holders of a role's configured admin
+/// role can manage that role as well.
+#[inline]
+fn require_role_admin(env: &Env, role: &Symbol, caller: &Address) -> Result<(), BOBTError> {
+    if require_owner(env, caller).is_ok() {
+        return Ok(());
+    }
+    let admin_role = storage::get_role_admin(env, role);
+    if admin_role == ROLE_OWNER {
+        return Err(BOBTError::Unauthorized);
+    }
+    require_role(env, &admin_role, caller)
+}
+
 /// Require caller to be the owner
 #[inline]
 fn require_owner(env: &Env, caller: &Address) -> Result<(), BOBTError> {
-    let owner = get_owner(env).ok_or(BOBTError::NotInitialized)?;
-    if *caller != owner {
-        return Err(BOBTError::Unauthorized);
+    require_initialized(env)?;
+    // A renounced contract has no owner, so every caller is rejected
+    match get_owner(env) {
+        Some(owner) if *caller == owner => Ok(()),
+        _ => Err(BOBTError::Unauthorized),
     }
-    Ok(())
 }
 
 /// Validate amount is positive and within limits
@@ -104,10 +215,70 @@ fn validate_amount(amount: i128) -> Result<(), BOBTError> {
     Ok(())
 }
 
-/// Validate mint doesn't exceed max supply
+/// Validate a mint/burn request ID is non-empty and bounded
+#[inline]
+fn validate_request_id(request_id: &String) -> Result<(), BOBTError> {
+    if request_id.is_empty() || request_id.len() > MAX_REQUEST_ID_LEN {
+        return Err(BOBTError::InvalidRequestId);
+    }
+    Ok(())
+}
+
+/// Validate a configuration before it is stored
+#[inline]
+fn validate_config(config: &ContractConfig) -> Result<(), BOBTError> {
+    if config.max_mint_amount <= 0 || config.max_mint_amount > MAX_SUPPLY {
+        return Err(BOBTError::InvalidConfig);
+    }
+    if config.max_burn_amount <= 0 || config.max_burn_amount > MAX_SUPPLY {
+        return Err(BOBTError::InvalidConfig);
+    }
+    if config.min_transfer_amount < MIN_TRANSFER {
+        return Err(BOBTError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Validate a transfer fee configuration before it is stored
+#[inline]
+fn validate_fee_config(config: &FeeConfig) -> Result<(), BOBTError> {
+    if config.rate_bps > MAX_FEE_BPS {
+        return Err(BOBTError::InvalidConfig);
+    }
+    if config.min_fee < 0 || config.max_fee < config.min_fee || config.max_fee > MAX_SUPPLY {
+        return Err(BOBTError::InvalidConfig);
+    }
+    Ok(())
+}
+
+/// Compute the fee charged when `from` sends `amount` to `to`
+///
+/// Returns the fee collector and the fee, or None when no fee applies:
+/// fees are disabled, either side is exempt, or either side is the collector.
+fn transfer_fee(env: &Env, from: &Address, to: &Address, amount: i128) -> Option<(Address, i128)> {
+    let config = storage::get_fee_config(env)?;
+    if *from == config.collector || *to == config.collector {
+        return None;
+    }
+    if storage::is_fee_exempt(env, from) || storage::is_fee_exempt(env, to) {
+        return None;
+    }
+
+    let fee = (amount * config.rate_bps as i128 / BPS_DENOMINATOR)
+        .max(config.min_fee)
+        .min(config.max_fee);
+    if fee == 0 {
+        return None;
+    }
+    Some((config.collector, fee))
+}
+
+/// Validate mint doesn't exceed max supply, counting reserved mints
 #[inline]
 fn validate_mint_supply(env: &Env, amount: i128) -> Result<(), BOBTError> {
-    let current_supply = get_total_supply(env);
+    let current_supply = get_total_supply(env)
+        .checked_add(storage::get_reserved_supply(env))
+        .ok_or(BOBTError::OverflowError)?;
     let new_supply = current_supply.checked_add(amount).ok_or(BOBTError::OverflowError)?;
     if new_supply > MAX_SUPPLY {
         return Err(BOBTError::AmountTooLarge);
@@ -115,34 +286,82 @@ fn validate_mint_supply(env: &Env, amount: i128) -> Result<(), BOBTError> {
     Ok(())
 }
 
+/// Validate that minting `amount` keeps total supply within attested reserves
+///
+/// A no-op while the proof-of-reserves gate is off. Otherwise the latest
+/// attestation must be fresh and cover the supply after the mint, including
+/// supply reserved by pending two-phase mints.
+fn validate_reserve_backing(env: &Env, amount: i128) -> Result<(), BOBTError> {
+    let Some(config) = storage::get_reserve_config(env) else {
+        return Ok(());
+    };
+    let attestation = match storage::get_reserve_attestation(env) {
+        Some(attestation) if !is_attestation_stale(env, &config, attestation.as_of) => attestation,
+        _ => return Err(BOBTError::ReservesInsufficient),
+    };
+
+    let new_supply = get_total_supply(env)
+        .checked_add(storage::get_reserved_supply(env))
+        .and_then(|supply| supply.checked_add(amount))
+        .ok_or(BOBTError::OverflowError)?;
+    if new_supply > attestation.reserves {
+        return Err(BOBTError::ReservesInsufficient);
+    }
+    Ok(())
+}
+
+/// Check if reserves measured at `as_of` are older than the gate's `max_age`
+#[inline]
+fn is_attestation_stale(env: &Env, config: &ReserveConfig, as_of: u64) -> bool {
+    env.ledger().timestamp().saturating_sub(as_of) > config.max_age
+}
+
 // =============================================================================
 // INTERNAL BALANCE OPERATIONS
 // =============================================================================
 
 /// Internal mint function with all validations
-fn mint_internal(env: &Env, to: &Address, amount: i128) -> Result<(), BOBTError> {
+///
+/// Emits the SEP-41 `mint` event with `admin` as the issuing account.
+fn mint_internal(env: &Env, admin: &Address, to: &Address, amount: i128) -> Result<(), BOBTError> {
     validate_amount(amount)?;
+    let config = storage::get_config(env);
+    if amount > config.max_mint_amount {
+        return Err(BOBTError::MintLimitExceeded);
+    }
     validate_mint_supply(env, amount)?;
+    validate_reserve_backing(env, amount)?;
 
     let balance = get_balance(env, to);
     let new_balance = balance.checked_add(amount).ok_or(BOBTError::OverflowError)?;
+    if config.require_whitelist {
+        require_kyc_receive(env, to, new_balance)?;
+    }
     set_balance(env, to, new_balance);
 
     let supply = get_total_supply(env);
     let new_supply = supply.checked_add(amount).ok_or(BOBTError::OverflowError)?;
     set_total_supply(env, new_supply);
 
+    emit_mint(env, admin, to, amount);
+
     Ok(())
 }
 
 /// Internal burn function with all validations
+///
+/// Emits the SEP-41 `burn` event.
 fn burn_internal(env: &Env, from: &Address, amount: i128) -> Result<(), BOBTError> {
     validate_amount(amount)?;
+    if amount > storage::get_config(env).max_burn_amount {
+        return Err(BOBTError::BurnLimitExceeded);
+    }
 
     let balance = get_balance(env, from);
     if balance < amount {
         return Err(BOBTError::InsufficientBalance);
     }
+    require_spendable(env, from, balance, amount)?;
 
     let new_balance = balance.checked_sub(amount).ok_or(BOBTError::OverflowError)?;
     set_balance(env, from, new_balance);
@@ -151,17 +370,85 @@ fn burn_internal(env: &Env, from: &Address, amount: i128) -> Result<(), BOBTErro
     let new_supply = supply.checked_sub(amount).ok_or(BOBTError::OverflowError)?;
     set_total_supply(env, new_supply);
 
+    emit_burn(env, from, amount);
+
     Ok(())
 }
 
+/// Release an account's newest holds until its held funds fit in `balance`
+///
+/// Seizures take funds regardless of holds; releasing the holds they
+/// undercut keeps every remaining hold backed by tokens that still exist.
+fn release_holds_over_balance(env: &Env, account: &Address, balance: i128, operator: &Address) {
+    let mut held = storage::get_held_balance(env, account);
+    let hold_ids = storage::get_account_holds(env, account);
+    let mut index = hold_ids.len();
+    while held > balance && index > 0 {
+        index -= 1;
+        let hold_id = hold_ids.get_unchecked(index);
+        if let Some(hold) = storage::get_hold(env, &hold_id) {
+            storage::remove_hold(env, &hold_id, account);
+            if storage::is_hold_active(env, &hold) {
+                held -= hold.amount;
+                emit_hold_released(env, account, &hold_id, hold.amount, operator);
+            }
+        }
+    }
+}
+
 /// Internal transfer function with all validations
+///
+/// Emits the SEP-41 `transfer` event.
 fn transfer_internal(
     env: &Env,
     from: &Address,
     to: &Address,
     amount: i128,
+) -> Result<(), BOBTError> {
+    move_balance(env, from, to, amount, None, true)
+}
+
+/// Internal transfer that publishes an optional payment memo
+///
+/// With a memo the `transfer` event data is `(amount, memo)`; any fee leg
+/// keeps the plain SEP-41 event.
+fn transfer_internal_with_memo(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: &Bytes,
+) -> Result<(), BOBTError> {
+    move_balance(env, from, to, amount, Some(memo), true)
+}
+
+/// Internal transfer that never charges the transfer fee
+///
+/// For legs that settle an amount agreed elsewhere: the relayer fee of
+/// `transfer_with_authorization` and hold payouts arrive in full.
+fn transfer_internal_fee_free(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), BOBTError> {
+    move_balance(env, from, to, amount, None, false)
+}
+
+/// Move `amount` from `from` to `to`, deducting the transfer fee if `charge_fee`
+fn move_balance(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: Option<&Bytes>,
+    charge_fee: bool,
 ) -> Result<(), BOBTError> {
     validate_amount(amount)?;
+    let config = storage::get_config(env);
+    if amount < config.min_transfer_amount {
+        return Err(BOBTError::BelowMinTransfer);
+    }
 
     if from == to {
         return Err(BOBTError::CannotTransferToSelf);
@@ -171,18 +458,63 @@ fn transfer_internal(
     if from_balance < amount {
         return Err(BOBTError::InsufficientBalance);
     }
+    require_spendable(env, from, from_balance, amount)?;
+
+    // The fee comes out of the amount sent; the recipient gets the rest
+    let fee = if charge_fee {
+        transfer_fee(env, from, to, amount)
+    } else {
+        None
+    };
+    let net_amount = match &fee {
+        Some((_, fee_amount)) => amount - fee_amount,
+        None => amount,
+    };
+    if net_amount < config.min_transfer_amount {
+        return Err(BOBTError::BelowMinTransfer);
+    }
 
     let new_from_balance = from_balance
         .checked_sub(amount)
         .ok_or(BOBTError::OverflowError)?;
-    set_balance(env, from, new_from_balance);
 
     let to_balance = get_balance(env, to);
     let new_to_balance = to_balance
-        .checked_add(amount)
+        .checked_add(net_amount)
         .ok_or(BOBTError::OverflowError)?;
+
+    // Permissioned mode: both sides must be approved and within tier limits
+    if config.require_whitelist {
+        require_kyc_send(env, from, amount)?;
+        require_kyc_receive(env, to, new_to_balance)?;
+    }
+
+    let collector_balance = match &fee {
+        Some((collector, fee_amount)) => {
+            let balance = get_balance(env, collector)
+                .checked_add(*fee_amount)
+                .ok_or(BOBTError::OverflowError)?;
+            require_fee_collector(env, collector, balance)?;
+            balance
+        }
+        None => 0,
+    };
+
+    set_balance(env, from, new_from_balance);
     set_balance(env, to, new_to_balance);
 
+    match memo {
+        Some(memo) => emit_transfer_with_memo(env, from, to, net_amount, memo),
+        None => emit_transfer(env, from, to, net_amount),
+    }
+
+    if let Some((collector, fee_amount)) = fee {
+        set_balance(env, &collector, collector_balance);
+
+        emit_transfer(env, from, &collector, fee_amount);
+        emit_fee_charged(env, from, to, fee_amount, &collector);
+    }
+
     Ok(())
 }
 
@@ -216,10 +548,10 @@ impl BOBTToken {
         set_owner(&env, &owner);
 
         // Grant owner role
-        grant_role(&env, &ROLE_OWNER, &owner);
+        grant_role(&env, &ROLE_OWNER, &owner, None);
 
-        // Initialize as not paused
-        set_paused(&env, false);
+        // Initialize with no operation paused
+        storage::set_pause_flags(&env, 0);
 
         // Extend instance TTL
         extend_instance_ttl(&env);
@@ -267,6 +599,22 @@ impl BOBTToken {
         get_balance(&env, &id)
     }
 
+    /// Get the balance of an account at the end of a past ledger
+    ///
+    /// Returns None for future ledgers and for ledgers older than the
+    /// retained checkpoints (`MAX_BALANCE_CHECKPOINTS` changes per account).
+    pub fn balance_at(env: Env, id: Address, ledger: u32) -> Option<i128> {
+        storage::get_balance_at(&env, &id, ledger)
+    }
+
+    /// Get the total supply at the end of a past ledger
+    ///
+    /// Returns None for future ledgers and for ledgers older than the
+    /// retained checkpoints (`MAX_SUPPLY_CHECKPOINTS` changes).
+    pub fn total_supply_at(env: Env, ledger: u32) -> Option<i128> {
+        storage::get_total_supply_at(&env, ledger)
+    }
+
     /// Get the allowance for a spender
     ///
     /// # Arguments
@@ -296,14 +644,205 @@ impl BOBTToken {
     ) -> Result<(), BOBTError> {
         from.require_auth();
 
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_APPROVE)?;
         require_not_frozen(&env, &from)?;
 
         set_allowance(&env, &from, &spender, amount, expiration_ledger);
 
+        emit_approve(&env, &from, &spender, amount, expiration_ledger);
+
+        Ok(())
+    }
+
+    /// Approve a spender with an off-chain ed25519 signature of the owner
+    ///
+    /// Anyone can submit the signature, so holders without XLM can approve
+    /// through a relayer. The signed digest is built by
+    /// `signature::permit_digest` and binds the network, this contract,
+    /// every argument and the owner's next nonce.
+    ///
+    /// The signature is checked against the owner's master key, not the
+    /// account's signers or thresholds (see `signature::verify`).
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (a Stellar account)
+    /// * `spender` - The spender address
+    /// * `amount` - The maximum amount to allow
+    /// * `expiration_ledger` - The ledger sequence when allowance expires
+    /// * `nonce` - The owner's next nonce (see `nonce`)
+    /// * `signature` - The owner's ed25519 signature of the digest
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If approvals are paused
+    /// * `AccountFrozen` - If owner account is frozen
+    /// * `InvalidNonce` - If nonce is not the owner's next nonce
+    /// * `InvalidSignature` - If owner is not an account address
+    ///
+    /// An invalid signature aborts the invocation with a host error instead
+    /// of returning `InvalidSignature`; see `signature::verify`.
+    pub fn permit(
+        env: Env,
+        owner: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), BOBTError> {
+        require_not_paused(&env, PAUSE_APPROVE)?;
+        require_not_frozen(&env, &owner)?;
+
+        if nonce != storage::get_nonce(&env, &owner) {
+            return Err(BOBTError::InvalidNonce);
+        }
+
+        let digest =
+            signature::permit_digest(&env, &owner, &spender, amount, expiration_ledger, nonce);
+        signature::verify(&env, &owner, &digest, &signature)?;
+
+        storage::set_nonce(&env, &owner, nonce + 1);
+        set_allowance(&env, &owner, &spender, amount, expiration_ledger);
+
+        emit_approve(&env, &owner, &spender, amount, expiration_ledger);
+
+        Ok(())
+    }
+
+    /// Get the next permit nonce of an account
+    pub fn nonce(env: Env, owner: Address) -> u64 {
+        storage::get_nonce(&env, &owner)
+    }
+
+    /// Transfer tokens with an off-chain ed25519 signature of the holder
+    ///
+    /// A relayer submits the holder's signed authorization and is paid `fee`
+    /// in BOBT from the holder in the same invocation, so holders need no
+    /// XLM. The transfer fee applies to `amount` but not to the relayer fee.
+    /// Authorizations use random 32-byte nonces and each nonce can be used
+    /// once. The signed digest is built by
+    /// `signature::transfer_authorization_digest`.
+    ///
+    /// The signature is checked against the holder's master key, not the
+    /// account's signers or thresholds (see `signature::verify`), so a
+    /// multisig holder can be debited on one key's signature.
+    ///
+    /// # Arguments
+    /// * `from` - The holder address (a Stellar account)
+    /// * `to` - The recipient address
+    /// * `amount` - The amount to transfer
+    /// * `fee` - The BOBT fee paid to the relayer (0 for none)
+    /// * `relayer` - The address receiving the fee
+    /// * `valid_until_ledger` - The last ledger the authorization can be used
+    /// * `nonce` - Unique nonce chosen by the holder
+    /// * `signature` - The holder's ed25519 signature of the digest
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If transfers are paused
+    /// * `AccountFrozen` - If holder, recipient or relayer is frozen
+    /// * `Expired` - If `valid_until_ledger` has passed
+    /// * `InvalidExpiration` - If `valid_until_ledger` is further away than
+    ///   the maximum entry TTL, which the used nonce must outlive
+    /// * `NegativeAmount` - If fee is negative
+    /// * `InvalidNonce` - If the nonce was already used or canceled
+    /// * `InvalidSignature` - If holder is not an account address
+    /// * `InsufficientBalance` - If holder cannot cover amount plus fee
+    ///
+    /// An invalid signature aborts the invocation with a host error instead
+    /// of returning `InvalidSignature`; see `signature::verify`.
+    pub fn transfer_with_authorization(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        fee: i128,
+        relayer: Address,
+        valid_until_ledger: u32,
+        nonce: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), BOBTError> {
+        require_not_paused(&env, PAUSE_TRANSFER)?;
+        require_not_frozen(&env, &from)?;
+        require_not_frozen(&env, &to)?;
+        require_not_frozen(&env, &relayer)?;
+
+        let current_ledger = env.ledger().sequence();
+        if current_ledger > valid_until_ledger {
+            return Err(BOBTError::Expired);
+        }
+        if valid_until_ledger - current_ledger > env.storage().max_ttl() {
+            return Err(BOBTError::InvalidExpiration);
+        }
+        if fee < 0 {
+            return Err(BOBTError::NegativeAmount);
+        }
+        if storage::is_authorization_used(&env, &from, &nonce) {
+            return Err(BOBTError::InvalidNonce);
+        }
+
+        let digest = signature::transfer_authorization_digest(
+            &env,
+            &from,
+            &to,
+            amount,
+            fee,
+            &relayer,
+            valid_until_ledger,
+            &nonce,
+        );
+        signature::verify(&env, &from, &digest, &signature)?;
+        storage::mark_authorization_used(&env, &from, &nonce, valid_until_ledger);
+
+        transfer_internal(&env, &from, &to, amount)?;
+        if fee > 0 {
+            transfer_internal_fee_free(&env, &from, &relayer, fee)?;
+        }
+
+        emit_authorization_used(&env, &from, &nonce, &relayer, fee);
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Cancel an unused transfer authorization with a signature of the holder
+    ///
+    /// The canceled nonce is kept for the maximum entry TTL, which outlives
+    /// any authorization `transfer_with_authorization` accepts today.
+    ///
+    /// # Arguments
+    /// * `from` - The holder address (a Stellar account)
+    /// * `nonce` - The nonce of the authorization to cancel
+    /// * `signature` - The holder's ed25519 signature of the cancel digest
+    ///
+    /// # Errors
+    /// * `InvalidNonce` - If the nonce was already used or canceled
+    /// * `InvalidSignature` - If holder is not an account address
+    ///
+    /// An invalid signature aborts the invocation with a host error.
+    pub fn cancel_authorization(
+        env: Env,
+        from: Address,
+        nonce: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), BOBTError> {
+        if storage::is_authorization_used(&env, &from, &nonce) {
+            return Err(BOBTError::InvalidNonce);
+        }
+
+        let digest = signature::cancel_authorization_digest(&env, &from, &nonce);
+        signature::verify(&env, &from, &digest, &signature)?;
+        storage::mark_authorization_used(&env, &from, &nonce, u32::MAX);
+
+        emit_authorization_canceled(&env, &from, &nonce);
+
         Ok(())
     }
 
+    /// Check if a transfer authorization nonce was used or canceled
+    pub fn authorization_state(env: Env, from: Address, nonce: BytesN<32>) -> bool {
+        storage::is_authorization_used(&env, &from, &nonce)
+    }
+
     /// Transfer tokens from the caller to another address
     ///
     /// # Arguments
@@ -318,7 +857,7 @@ impl BOBTToken {
     pub fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), BOBTError> {
         from.require_auth();
 
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_TRANSFER)?;
         require_not_frozen(&env, &from)?;
         require_not_frozen(&env, &to)?;
 
@@ -330,6 +869,46 @@ impl BOBTToken {
         Ok(())
     }
 
+    /// Transfer tokens with a payment memo for merchant reconciliation
+    ///
+    /// The memo (e.g. a QR invoice ID) is published in the `transfer` event
+    /// data as `(amount, memo)` so point-of-sale systems can match payments.
+    ///
+    /// # Arguments
+    /// * `from` - The sender address (must authorize)
+    /// * `to` - The recipient address
+    /// * `amount` - The amount to transfer
+    /// * `memo` - The payment reference, at most `MAX_MEMO_LEN` bytes
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If transfers are paused
+    /// * `AccountFrozen` - If either account is frozen
+    /// * `MemoTooLong` - If the memo exceeds `MAX_MEMO_LEN` bytes
+    /// * `InsufficientBalance` - If sender has insufficient balance
+    pub fn transfer_with_memo(
+        env: Env,
+        from: Address,
+        to: Address,
+        amount: i128,
+        memo: Bytes,
+    ) -> Result<(), BOBTError> {
+        from.require_auth();
+
+        require_not_paused(&env, PAUSE_TRANSFER)?;
+        require_not_frozen(&env, &from)?;
+        require_not_frozen(&env, &to)?;
+
+        if memo.len() > MAX_MEMO_LEN {
+            return Err(BOBTError::MemoTooLong);
+        }
+
+        transfer_internal_with_memo(&env, &from, &to, amount, &memo)?;
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
     /// Transfer tokens from one address to another using allowance
     ///
     /// # Arguments
@@ -351,7 +930,7 @@ impl BOBTToken {
     ) -> Result<(), BOBTError> {
         spender.require_auth();
 
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_TRANSFER)?;
         require_not_frozen(&env, &from)?;
         require_not_frozen(&env, &to)?;
         require_not_frozen(&env, &spender)?;
@@ -369,6 +948,56 @@ impl BOBTToken {
         Ok(())
     }
 
+    /// Transfer tokens from the caller to several recipients at once
+    ///
+    /// Every leg is validated before any balance moves and the batch is
+    /// applied all or nothing. One `transfer` event is emitted per leg.
+    ///
+    /// # Arguments
+    /// * `from` - The sender address (must authorize)
+    /// * `recipients` - (recipient, amount) pairs, at most `MAX_BATCH_SIZE`
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If transfers are paused
+    /// * `InvalidBatchSize` - If the batch is empty or too large
+    /// * `AccountFrozen` - If the sender or any recipient is frozen
+    /// * `NegativeAmount` - If any amount is not positive
+    /// * `InsufficientBalance` - If the sender cannot cover the batch total
+    pub fn batch_transfer(
+        env: Env,
+        from: Address,
+        recipients: Vec<(Address, i128)>,
+    ) -> Result<(), BOBTError> {
+        from.require_auth();
+
+        require_not_paused(&env, PAUSE_TRANSFER)?;
+        if recipients.is_empty() || recipients.len() > MAX_BATCH_SIZE {
+            return Err(BOBTError::InvalidBatchSize);
+        }
+        require_not_frozen(&env, &from)?;
+
+        // Validate every leg before moving any funds
+        let mut total: i128 = 0;
+        for (to, amount) in recipients.iter() {
+            require_not_frozen(&env, &to)?;
+            validate_amount(amount)?;
+            total = total.checked_add(amount).ok_or(BOBTError::OverflowError)?;
+        }
+        let from_balance = get_balance(&env, &from);
+        if from_balance < total {
+            return Err(BOBTError::InsufficientBalance);
+        }
+        require_spendable(&env, &from, from_balance, total)?;
+
+        for (to, amount) in recipients.iter() {
+            transfer_internal(&env, &from, &to, amount)?;
+        }
+
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
     // =========================================================================
     // MINT OPERATIONS (MINTER_ROLE)
     // =========================================================================
@@ -381,10 +1010,14 @@ impl BOBTToken {
     /// * `amount` - The amount to mint (in stroops)
     /// * `request_id` - Unique request ID for tracking (bank transaction ID)
     ///
+    /// The amount is deducted from the minter's mint allowance.
+    ///
     /// # Errors
     /// * `Unauthorized` - If minter doesn't have MINTER role
+    /// * `MinterAllowanceExceeded` - If amount exceeds the minter's allowance
     /// * `ContractPaused` - If contract is paused
     /// * `AccountFrozen` - If recipient is frozen
+    /// * `InvalidRequestId` - If request_id is empty or too long
     /// * `RequestAlreadyExists` - If request_id already used
     /// * `AmountTooLarge` - If mint would exceed MAX_SUPPLY
     pub fn admin_mint(
@@ -397,16 +1030,26 @@ impl BOBTToken {
         minter.require_auth();
 
         require_role(&env, &ROLE_MINTER, &minter)?;
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_MINT)?;
         require_not_frozen(&env, &to)?;
 
         // Check for duplicate request (idempotency)
-        if storage::mint_request_exists(&env, &request_id) {
+        validate_request_id(&request_id)?;
+        if storage::mint_request_exists(&env, &request_id)
+            || storage::mint_reservation_exists(&env, &request_id)
+        {
             return Err(BOBTError::RequestAlreadyExists);
         }
 
-        // Mint tokens
-        mint_internal(&env, &to, amount)?;
+        // Consume minter allowance
+        let allowance = storage::get_minter_allowance(&env, &minter);
+        if amount > allowance {
+            return Err(BOBTError::MinterAllowanceExceeded);
+        }
+
+        // Mint tokens
+        mint_internal(&env, &minter, &to, amount)?;
+        storage::set_minter_allowance(&env, &minter, allowance - amount);
 
         // Store request for audit trail
         let request = MintRequest {
@@ -419,8 +1062,8 @@ impl BOBTToken {
         };
         storage::store_mint_request(&env, &request_id, &request);
 
-        // Emit event
-        emit_mint(&env, &to, amount, &request_id, &minter);
+        // Emit audit event (SEP-41 `mint` is emitted by mint_internal)
+        emit_mint_request(&env, &to, amount, &request_id, &minter);
 
         // Extend TTL
         extend_instance_ttl(&env);
@@ -428,41 +1071,333 @@ impl BOBTToken {
         Ok(())
     }
 
-    /// Legacy mint function (deprecated - use admin_mint)
+    /// Legacy mint entrypoint (disabled since v2 - use admin_mint)
+    ///
+    /// The v1 implementation minted without any authorization. The entrypoint
+    /// is kept so existing integrations fail loudly instead of hitting a
+    /// missing function.
+    ///
+    /// # Errors
+    /// * `LegacyMintDisabled` - Always
     pub fn mint(
+        _env: Env,
+        _to: Address,
+        _amount: i128,
+        _request_id: String,
+    ) -> Result<(), BOBTError> {
+        Err(BOBTError::LegacyMintDisabled)
+    }
+
+    // =========================================================================
+    // TWO-PHASE MINT (MINTER_ROLE)
+    // =========================================================================
+
+    /// Reserve a mint against a bank deposit that may still be reversed
+    ///
+    /// The amount counts against `MAX_SUPPLY` and the minter's allowance
+    /// right away but is not credited until `confirm_mint`. It is reported
+    /// by `reserved_supply`, not `total_supply`. The request ID is used from
+    /// this point on, even if the reservation is later voided.
+    ///
+    /// # Arguments
+    /// * `minter` - The minter address (must have MINTER role and authorize)
+    /// * `request_id` - Unique request ID (bank transaction ID)
+    /// * `to` - The recipient address
+    /// * `amount` - The amount to reserve (in stroops)
+    /// * `confirm_by_ledger` - Last ledger the mint can be confirmed
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If minter doesn't have MINTER role
+    /// * `ContractPaused` - If minting is paused
+    /// * `AccountFrozen` - If recipient is frozen
+    /// * `InvalidRequestId` - If request_id is empty or too long
+    /// * `RequestAlreadyExists` - If request_id is minted or reserved
+    /// * `InvalidExpiration` - If confirm_by_ledger has already passed or is
+    ///   further out than the maximum storage TTL
+    /// * `MintLimitExceeded` - If amount exceeds the per-mint limit
+    /// * `MinterAllowanceExceeded` - If amount exceeds the minter's allowance
+    /// * `AmountTooLarge` - If supply plus reservations would exceed MAX_SUPPLY
+    /// * `ReservesInsufficient` - If the reserve gate is on and supply plus
+    ///   reservations would exceed the latest fresh attestation
+    /// * `AccountNotApproved` / `KycLimitExceeded` - If in permissioned
+    ///   mode and the recipient is not approved or would exceed its tier
+    pub fn reserve_mint(
         env: Env,
+        minter: Address,
+        request_id: String,
         to: Address,
         amount: i128,
-        request_id: String,
+        confirm_by_ledger: u32,
     ) -> Result<(), BOBTError> {
-        // For backwards compatibility, use contract as minter
-        // In production, this should be removed or require proper auth
-        require_not_paused(&env)?;
+        minter.require_auth();
+
+        require_role(&env, &ROLE_MINTER, &minter)?;
+        require_not_paused(&env, PAUSE_MINT)?;
         require_not_frozen(&env, &to)?;
 
-        if storage::mint_request_exists(&env, &request_id) {
+        validate_request_id(&request_id)?;
+        if storage::mint_request_exists(&env, &request_id)
+            || storage::mint_reservation_exists(&env, &request_id)
+        {
             return Err(BOBTError::RequestAlreadyExists);
         }
+        let current_ledger = env.ledger().sequence();
+        if confirm_by_ledger < current_ledger
+            || confirm_by_ledger - current_ledger > env.storage().max_ttl()
+        {
+            return Err(BOBTError::InvalidExpiration);
+        }
+
+        validate_amount(amount)?;
+        let config = storage::get_config(&env);
+        if amount > config.max_mint_amount {
+            return Err(BOBTError::MintLimitExceeded);
+        }
+        let allowance = storage::get_minter_allowance(&env, &minter);
+        if amount > allowance {
+            return Err(BOBTError::MinterAllowanceExceeded);
+        }
+        validate_mint_supply(&env, amount)?;
+        validate_reserve_backing(&env, amount)?;
+        if config.require_whitelist {
+            let new_balance = get_balance(&env, &to)
+                .checked_add(amount)
+                .ok_or(BOBTError::OverflowError)?;
+            require_kyc_receive(&env, &to, new_balance)?;
+        }
 
-        mint_internal(&env, &to, amount)?;
+        storage::set_minter_allowance(&env, &minter, allowance - amount);
+        storage::set_reserved_supply(&env, storage::get_reserved_supply(&env) + amount);
 
-        let minter = env.current_contract_address();
-        let request = MintRequest {
+        let reservation = MintReservation {
             to: to.clone(),
             amount,
+            minter: minter.clone(),
+            ledger: env.ledger().sequence(),
+            confirm_by_ledger,
+        };
+        storage::store_mint_reservation(&env, &request_id, &reservation);
+        storage::mark_request_id_used(&env, RequestKind::Mint, &request_id);
+
+        emit_mint_reserved(&env, &to, amount, &request_id, confirm_by_ledger, &minter);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Confirm a reserved mint once the bank deposit has settled
+    ///
+    /// Credits the recipient and records the mint request as `admin_mint`
+    /// would, attributed to the minter who reserved it. Only that minter or
+    /// a master minter can confirm.
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If minting is paused
+    /// * `RequestNotFound` - If no reservation exists for request_id
+    /// * `Unauthorized` - If caller is neither the reserving minter (with
+    ///   MINTER role) nor a MASTER_MINTER
+    /// * `Expired` - If confirm_by_ledger has passed (void it instead)
+    /// * `AccountFrozen` - If recipient is frozen
+    pub fn confirm_mint(env: Env, minter: Address, request_id: String) -> Result<(), BOBTError> {
+        minter.require_auth();
+
+        require_not_paused(&env, PAUSE_MINT)?;
+
+        let reservation =
+            storage::get_mint_reservation(&env, &request_id).ok_or(BOBTError::RequestNotFound)?;
+        require_reservation_operator(&env, &minter, &reservation)?;
+        if env.ledger().sequence() > reservation.confirm_by_ledger {
+            return Err(BOBTError::Expired);
+        }
+        require_not_frozen(&env, &reservation.to)?;
+
+        // Release the reservation before minting so it is not counted twice
+        storage::remove_mint_reservation(&env, &request_id);
+        let reserved = storage::get_reserved_supply(&env);
+        storage::set_reserved_supply(&env, reserved - reservation.amount);
+
+        mint_internal(&env, &reservation.minter, &reservation.to, reservation.amount)?;
+
+        let request = MintRequest {
+            to: reservation.to.clone(),
+            amount: reservation.amount,
             ledger: env.ledger().sequence(),
             timestamp: env.ledger().timestamp(),
-            minter: minter.clone(),
+            minter: reservation.minter.clone(),
             external_ref: request_id.clone(),
         };
         storage::store_mint_request(&env, &request_id, &request);
 
-        emit_mint(&env, &to, amount, &request_id, &minter);
+        emit_mint_request(
+            &env,
+            &reservation.to,
+            reservation.amount,
+            &request_id,
+            &reservation.minter,
+        );
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Discard a reserved mint, e.g. after the bank transfer was reversed
+    ///
+    /// Returns the amount to the reserving minter's allowance, unless that
+    /// minter no longer holds the MINTER role. Expired reservations can
+    /// still be voided, and voiding works while paused. Only the reserving
+    /// minter or a master minter can void, and the request ID stays used.
+    ///
+    /// # Errors
+    /// * `RequestNotFound` - If no reservation exists for request_id
+    /// * `Unauthorized` - If caller is neither the reserving minter (with
+    ///   MINTER role) nor a MASTER_MINTER
+    /// * `OverflowError` - If the refund would overflow the allowance
+    pub fn void_mint(env: Env, minter: Address, request_id: String) -> Result<(), BOBTError> {
+        minter.require_auth();
+
+        let reservation =
+            storage::get_mint_reservation(&env, &request_id).ok_or(BOBTError::RequestNotFound)?;
+        require_reservation_operator(&env, &minter, &reservation)?;
+
+        storage::remove_mint_reservation(&env, &request_id);
+        let reserved = storage::get_reserved_supply(&env);
+        storage::set_reserved_supply(&env, reserved - reservation.amount);
+
+        if has_role(&env, &ROLE_MINTER, &reservation.minter) {
+            let allowance = storage::get_minter_allowance(&env, &reservation.minter)
+                .checked_add(reservation.amount)
+                .ok_or(BOBTError::OverflowError)?;
+            storage::set_minter_allowance(&env, &reservation.minter, allowance);
+        }
+
+        emit_mint_voided(
+            &env,
+            &reservation.to,
+            reservation.amount,
+            &request_id,
+            &reservation.minter,
+        );
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get a pending mint reservation by request ID
+    pub fn get_mint_reservation(
+        env: Env,
+        request_id: String,
+    ) -> Result<MintReservation, BOBTError> {
+        storage::get_mint_reservation(&env, &request_id).ok_or(BOBTError::RequestNotFound)
+    }
+
+    /// Supply reserved by pending two-phase mints (not part of `total_supply`)
+    pub fn reserved_supply(env: Env) -> i128 {
+        storage::get_reserved_supply(&env)
+    }
+
+    // =========================================================================
+    // MINTER ALLOWANCES (MASTER_MINTER_ROLE)
+    // =========================================================================
+
+    /// Set a minter's mint allowance (MASTER_MINTER_ROLE required)
+    ///
+    /// # Arguments
+    /// * `master` - The master minter (must authorize)
+    /// * `minter` - The minter whose allowance is set
+    /// * `allowance` - The new remaining allowance (in stroops)
+    pub fn configure_minter(
+        env: Env,
+        master: Address,
+        minter: Address,
+        allowance: i128,
+    ) -> Result<(), BOBTError> {
+        master.require_auth();
+
+        require_role(&env, &ROLE_MASTER_MINTER, &master)?;
+        if allowance < 0 {
+            return Err(BOBTError::NegativeAmount);
+        }
+
+        storage::set_minter_allowance(&env, &minter, allowance);
+
+        emit_minter_allowance(&env, &minter, allowance, &master);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Raise a minter's mint allowance (MASTER_MINTER_ROLE required)
+    pub fn increase_minter_allowance(
+        env: Env,
+        master: Address,
+        minter: Address,
+        amount: i128,
+    ) -> Result<(), BOBTError> {
+        master.require_auth();
+
+        require_role(&env, &ROLE_MASTER_MINTER, &master)?;
+        validate_amount(amount)?;
+
+        let allowance = storage::get_minter_allowance(&env, &minter)
+            .checked_add(amount)
+            .ok_or(BOBTError::OverflowError)?;
+        storage::set_minter_allowance(&env, &minter, allowance);
+
+        emit_minter_allowance(&env, &minter, allowance, &master);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Lower a minter's mint allowance (MASTER_MINTER_ROLE required)
+    ///
+    /// # Errors
+    /// * `MinterAllowanceExceeded` - If amount exceeds the remaining allowance
+    pub fn decrease_minter_allowance(
+        env: Env,
+        master: Address,
+        minter: Address,
+        amount: i128,
+    ) -> Result<(), BOBTError> {
+        master.require_auth();
+
+        require_role(&env, &ROLE_MASTER_MINTER, &master)?;
+        validate_amount(amount)?;
+
+        let current = storage::get_minter_allowance(&env, &minter);
+        if amount > current {
+            return Err(BOBTError::MinterAllowanceExceeded);
+        }
+        let allowance = current - amount;
+        storage::set_minter_allowance(&env, &minter, allowance);
+
+        emit_minter_allowance(&env, &minter, allowance, &master);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Remove a minter's allowance so it can no longer mint (MASTER_MINTER_ROLE required)
+    ///
+    /// The MINTER role itself is left untouched and can still be used for burns.
+    pub fn remove_minter(env: Env, master: Address, minter: Address) -> Result<(), BOBTError> {
+        master.require_auth();
+
+        require_role(&env, &ROLE_MASTER_MINTER, &master)?;
+
+        storage::remove_minter_allowance(&env, &minter);
+
+        emit_minter_removed(&env, &minter, &master);
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
+    /// Get a minter's remaining mint allowance
+    pub fn minter_allowance(env: Env, minter: Address) -> i128 {
+        storage::get_minter_allowance(&env, &minter)
+    }
+
     // =========================================================================
     // BURN OPERATIONS
     // =========================================================================
@@ -479,11 +1414,10 @@ impl BOBTToken {
     pub fn burn(env: Env, from: Address, amount: i128) -> Result<(), BOBTError> {
         from.require_auth();
 
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_BURN)?;
 
         burn_internal(&env, &from, amount)?;
 
-        emit_user_burn(&env, &from, amount);
         extend_instance_ttl(&env);
 
         Ok(())
@@ -503,7 +1437,7 @@ impl BOBTToken {
     ) -> Result<(), BOBTError> {
         spender.require_auth();
 
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_BURN)?;
 
         // Spend allowance
         spend_allowance(&env, &from, &spender, amount)
@@ -511,7 +1445,6 @@ impl BOBTToken {
 
         burn_internal(&env, &from, amount)?;
 
-        emit_user_burn(&env, &from, amount);
         extend_instance_ttl(&env);
 
         Ok(())
@@ -527,6 +1460,7 @@ impl BOBTToken {
     ///
     /// # Errors
     /// * `Unauthorized` - If operator doesn't have MINTER role
+    /// * `InvalidRequestId` - If request_id is empty or too long
     /// * `RequestAlreadyExists` - If request_id already used
     pub fn admin_burn(
         env: Env,
@@ -539,9 +1473,10 @@ impl BOBTToken {
         from.require_auth();
 
         require_role(&env, &ROLE_MINTER, &operator)?;
-        require_not_paused(&env)?;
+        require_not_paused(&env, PAUSE_BURN)?;
 
         // Check for duplicate request
+        validate_request_id(&request_id)?;
         if storage::burn_request_exists(&env, &request_id) {
             return Err(BOBTError::RequestAlreadyExists);
         }
@@ -560,7 +1495,7 @@ impl BOBTToken {
         };
         storage::store_burn_request(&env, &request_id, &request);
 
-        emit_burn(&env, &from, amount, &request_id, &operator);
+        emit_burn_request(&env, &from, amount, &request_id, &operator);
         extend_instance_ttl(&env);
 
         Ok(())
@@ -570,74 +1505,169 @@ impl BOBTToken {
     // PAUSE OPERATIONS (PAUSER_ROLE)
     // =========================================================================
 
-    /// Pause the contract (PAUSER_ROLE required)
+    /// Pause every operation class (PAUSER_ROLE required)
+    ///
+    /// Blocks transfers, mints, burns and approvals for emergency response.
     ///
-    /// When paused, most operations are blocked for emergency response.
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks PAUSER_ROLE
+    /// * `ContractPaused` - If everything is already paused
     pub fn pause(env: Env, pauser: Address) -> Result<(), BOBTError> {
+        Self::pause_operations(env, pauser, PAUSE_ALL)
+    }
+
+    /// Unpause every operation class (PAUSER_ROLE required)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks PAUSER_ROLE
+    /// * `ContractNotPaused` - If nothing is paused
+    pub fn unpause(env: Env, pauser: Address) -> Result<(), BOBTError> {
+        Self::unpause_operations(env, pauser, PAUSE_ALL)
+    }
+
+    /// Pause selected operation classes (PAUSER_ROLE required)
+    ///
+    /// # Arguments
+    /// * `pauser` - Address with PAUSER_ROLE (must authorize)
+    /// * `flags` - Bitmask of transfer (1), mint (2), burn (4) and approve (8)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks PAUSER_ROLE
+    /// * `InvalidConfig` - If `flags` is empty or has unknown bits
+    /// * `ContractPaused` - If every selected class is already paused
+    pub fn pause_operations(env: Env, pauser: Address, flags: u32) -> Result<(), BOBTError> {
         pauser.require_auth();
 
         require_role(&env, &ROLE_PAUSER, &pauser)?;
-        require_not_paused(&env)?;
+        validate_pause_flags(flags)?;
 
-        set_paused(&env, true);
+        let current = storage::get_pause_flags(&env);
+        let newly_paused = flags & !current;
+        if newly_paused == 0 {
+            return Err(BOBTError::ContractPaused);
+        }
+
+        storage::set_pause_flags(&env, current | flags);
 
-        emit_pause(&env, &pauser);
+        for flag in [PAUSE_TRANSFER, PAUSE_MINT, PAUSE_BURN, PAUSE_APPROVE] {
+            if newly_paused & flag != 0 {
+                emit_pause(&env, flag, &pauser);
+            }
+        }
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Unpause the contract (PAUSER_ROLE required)
-    pub fn unpause(env: Env, pauser: Address) -> Result<(), BOBTError> {
+    /// Unpause selected operation classes (PAUSER_ROLE required)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks PAUSER_ROLE
+    /// * `InvalidConfig` - If `flags` is empty or has unknown bits
+    /// * `ContractNotPaused` - If none of the selected classes is paused
+    pub fn unpause_operations(env: Env, pauser: Address, flags: u32) -> Result<(), BOBTError> {
         pauser.require_auth();
 
         require_role(&env, &ROLE_PAUSER, &pauser)?;
-        require_paused(&env)?;
+        validate_pause_flags(flags)?;
+
+        let current = storage::get_pause_flags(&env);
+        let newly_unpaused = flags & current;
+        if newly_unpaused == 0 {
+            return Err(BOBTError::ContractNotPaused);
+        }
 
-        set_paused(&env, false);
+        storage::set_pause_flags(&env, current & !flags);
 
-        emit_unpause(&env, &pauser);
+        for flag in [PAUSE_TRANSFER, PAUSE_MINT, PAUSE_BURN, PAUSE_APPROVE] {
+            if newly_unpaused & flag != 0 {
+                emit_unpause(&env, flag, &pauser);
+            }
+        }
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Check if the contract is currently paused
+    /// Check if any operation class is currently paused
     pub fn is_paused(env: Env) -> bool {
         is_paused(&env)
     }
 
+    /// Get the pause bitmask: transfer (1), mint (2), burn (4), approve (8)
+    pub fn pause_flags(env: Env) -> u32 {
+        storage::get_pause_flags(&env)
+    }
+
     // =========================================================================
     // BLACKLIST/FREEZE OPERATIONS (BLACKLISTER_ROLE)
     // =========================================================================
 
     /// Freeze an account (BLACKLISTER_ROLE required)
     ///
-    /// Frozen accounts cannot send or receive tokens.
-    pub fn freeze(env: Env, blacklister: Address, account: Address) -> Result<(), BOBTError> {
+    /// Frozen accounts cannot send or receive tokens. A freeze with an
+    /// expiration ledger lapses on its own after that ledger. Freezing an
+    /// account whose freeze lapsed, or that carries a legacy flag without a
+    /// record, replaces it.
+    ///
+    /// # Arguments
+    /// * `blacklister` - Address with BLACKLISTER_ROLE (must authorize)
+    /// * `account` - The account to freeze
+    /// * `reason` - Reason code for compliance reporting
+    /// * `case_ref` - Compliance case or legal reference
+    /// * `expiration_ledger` - Last frozen ledger (None = until unfrozen)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks BLACKLISTER_ROLE
+    /// * `AccountFrozen` - If the account already has an active freeze record
+    /// * `InvalidExpiration` - If the expiration ledger is in the past
+    pub fn freeze(
+        env: Env,
+        blacklister: Address,
+        account: Address,
+        reason: FreezeReason,
+        case_ref: String,
+        expiration_ledger: Option<u32>,
+    ) -> Result<(), BOBTError> {
         blacklister.require_auth();
 
         require_role(&env, &ROLE_BLACKLISTER, &blacklister)?;
 
-        if is_frozen(&env, &account) {
-            return Err(BOBTError::AccountFrozen);
+        if let Some(existing) = storage::get_freeze_record(&env, &account) {
+            if storage::is_freeze_active(&env, &existing) {
+                return Err(BOBTError::AccountFrozen);
+            }
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if matches!(expiration_ledger, Some(expiration) if expiration < current_ledger) {
+            return Err(BOBTError::InvalidExpiration);
         }
 
-        freeze_account(&env, &account);
+        let record = FreezeRecord {
+            reason,
+            case_ref,
+            blacklister,
+            ledger: current_ledger,
+            expiration_ledger,
+        };
+        freeze_account(&env, &account, &record);
 
-        emit_freeze(&env, &account, &blacklister);
+        emit_freeze(&env, &account, &record);
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
     /// Unfreeze an account (BLACKLISTER_ROLE required)
+    ///
+    /// Also clears lapsed freezes from the frozen account list.
     pub fn unfreeze(env: Env, blacklister: Address, account: Address) -> Result<(), BOBTError> {
         blacklister.require_auth();
 
         require_role(&env, &ROLE_BLACKLISTER, &blacklister)?;
 
-        if !is_frozen(&env, &account) {
+        if !is_frozen(&env, &account) && storage::get_freeze_record(&env, &account).is_none() {
             return Err(BOBTError::AccountNotFrozen);
         }
 
@@ -654,6 +1684,42 @@ impl BOBTToken {
         is_frozen(&env, &account)
     }
 
+    /// Get the freeze record of an account (kept after it lapses until unfrozen)
+    pub fn freeze_record(env: Env, account: Address) -> Option<FreezeRecord> {
+        storage::get_freeze_record(&env, &account)
+    }
+
+    /// Length of the frozen account list
+    ///
+    /// Lapsed freezes stay listed until the account is unfrozen or frozen
+    /// again, so this can exceed the number of currently frozen accounts.
+    pub fn frozen_list_len(env: Env) -> u32 {
+        storage::get_frozen_count(&env)
+    }
+
+    /// List currently frozen accounts, one page of the frozen list at a time
+    ///
+    /// Reads list slots `start..start + limit` (at most `MAX_PAGE_SIZE`) and
+    /// skips accounts whose freeze has lapsed, so a page may hold fewer than
+    /// `limit` entries. Every slot read has its freeze entries extended to
+    /// the maximum TTL; a keeper should page through the whole list at least
+    /// once per maximum TTL.
+    pub fn frozen_accounts(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(storage::get_frozen_count(&env));
+
+        let mut accounts = Vec::new(&env);
+        for index in start..end {
+            if let Some(account) = storage::get_frozen_at(&env, index) {
+                if is_frozen(&env, &account) {
+                    accounts.push_back(account);
+                }
+            }
+        }
+        accounts
+    }
+
     /// Destroy frozen funds (clawback) - BLACKLISTER_ROLE required
     ///
     /// Burns all tokens from a frozen account. Account must be frozen first.
@@ -673,13 +1739,14 @@ impl BOBTToken {
         let balance = get_balance(&env, &account);
         if balance > 0 {
             // Burn without amount validation (clawback special case)
+            release_holds_over_balance(&env, &account, 0, &blacklister);
             set_balance(&env, &account, 0);
 
             let supply = get_total_supply(&env);
             let new_supply = supply.checked_sub(balance).ok_or(BOBTError::OverflowError)?;
             set_total_supply(&env, new_supply);
 
-            emit_clawback(&env, &account, balance, &blacklister);
+            emit_clawback(&env, &blacklister, &account, balance);
         }
 
         extend_instance_ttl(&env);
@@ -687,208 +1754,1412 @@ impl BOBTToken {
         Ok(())
     }
 
-    // =========================================================================
-    // RESCUE OPERATIONS (RESCUER_ROLE)
-    // =========================================================================
-
-    /// Rescue tokens sent to the contract by mistake (RESCUER_ROLE required)
+    /// Seize part of an account's balance - BLACKLISTER_ROLE required
     ///
-    /// Cannot rescue BOBT tokens (the contract's own token).
-    pub fn rescue_tokens(
+    /// Moves `amount` to a custody `destination`, or burns it when no
+    /// destination is given. The account does not need to be frozen, and the
+    /// seizure works while paused. `legal_ref` identifies the court order and
+    /// must be unique; the record is kept for the audit trail. Holds the
+    /// seizure leaves unbacked are released, newest first.
+    ///
+    /// # Arguments
+    /// * `blacklister` - Address with BLACKLISTER_ROLE (must authorize)
+    /// * `account` - The account to seize from
+    /// * `amount` - The amount to seize
+    /// * `destination` - Custody account, or None to burn
+    /// * `legal_ref` - Court order or case reference
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks BLACKLISTER_ROLE
+    /// * `InvalidRequestId` - If the legal reference is empty or too long
+    /// * `RequestAlreadyExists` - If the legal reference was already used
+    /// * `InsufficientBalance` - If the account holds less than `amount`
+    /// * `CannotTransferToSelf` - If the destination is the account itself
+    /// * `AccountFrozen` - If the destination is frozen
+    /// * `AccountNotApproved` / `KycLimitExceeded` - If in permissioned
+    ///   mode the destination may not receive the funds
+    pub fn clawback(
         env: Env,
-        rescuer: Address,
-        token: Address,
-        to: Address,
+        blacklister: Address,
+        account: Address,
         amount: i128,
+        destination: Option<Address>,
+        legal_ref: String,
     ) -> Result<(), BOBTError> {
-        rescuer.require_auth();
+        blacklister.require_auth();
 
-        require_role(&env, &ROLE_RESCUER, &rescuer)?;
+        require_role(&env, &ROLE_BLACKLISTER, &blacklister)?;
+        validate_amount(amount)?;
+        validate_request_id(&legal_ref)?;
 
-        // Cannot rescue own token
-        if token == env.current_contract_address() {
-            return Err(BOBTError::CannotRescueOwnToken);
+        if storage::clawback_exists(&env, &legal_ref) {
+            return Err(BOBTError::RequestAlreadyExists);
         }
 
-        // Transfer the foreign token
-        let token_client = TokenClient::new(&env, &token);
-        token_client.transfer(&env.current_contract_address(), &to, &amount);
-
-        emit_rescue(&env, &token, &to, amount, &rescuer);
-        extend_instance_ttl(&env);
-
-        Ok(())
-    }
+        let balance = get_balance(&env, &account);
+        if balance < amount {
+            return Err(BOBTError::InsufficientBalance);
+        }
 
-    // =========================================================================
-    // REQUEST TRACKING (AUDIT TRAIL)
-    // =========================================================================
+        match &destination {
+            Some(custody) => {
+                if *custody == account {
+                    return Err(BOBTError::CannotTransferToSelf);
+                }
+                require_not_frozen(&env, custody)?;
+
+                let custody_balance = get_balance(&env, custody);
+                let new_custody_balance = custody_balance
+                    .checked_add(amount)
+                    .ok_or(BOBTError::OverflowError)?;
+                if storage::get_config(&env).require_whitelist {
+                    require_kyc_receive(&env, custody, new_custody_balance)?;
+                }
+                release_holds_over_balance(&env, &account, balance - amount, &blacklister);
+                set_balance(&env, &account, balance - amount);
+                set_balance(&env, custody, new_custody_balance);
+
+                emit_transfer(&env, &account, custody, amount);
+            }
+            None => {
+                release_holds_over_balance(&env, &account, balance - amount, &blacklister);
+                set_balance(&env, &account, balance - amount);
+
+                let supply = get_total_supply(&env);
+                let new_supply = supply.checked_sub(amount).ok_or(BOBTError::OverflowError)?;
+                set_total_supply(&env, new_supply);
+
+                emit_clawback(&env, &blacklister, &account, amount);
+            }
+        }
 
-    /// Get a mint request by ID
-    pub fn get_mint_request(env: Env, request_id: String) -> Result<MintRequest, BOBTError> {
-        storage::get_mint_request(&env, &request_id).ok_or(BOBTError::RequestNotFound)
-    }
+        let record = ClawbackRecord {
+            account: account.clone(),
+            amount,
+            destination: destination.clone(),
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            blacklister: blacklister.clone(),
+            legal_ref: legal_ref.clone(),
+        };
+        storage::store_clawback(&env, &legal_ref, &record);
 
-    /// Get a burn request by ID
-    pub fn get_burn_request(env: Env, request_id: String) -> Result<BurnRequest, BOBTError> {
-        storage::get_burn_request(&env, &request_id).ok_or(BOBTError::RequestNotFound)
-    }
+        emit_seizure(&env, &account, amount, &legal_ref, &destination, &blacklister);
+        extend_instance_ttl(&env);
 
-    /// Check if a mint request exists
-    pub fn mint_request_exists(env: Env, request_id: String) -> bool {
-        storage::mint_request_exists(&env, &request_id)
+        Ok(())
     }
 
-    /// Check if a burn request exists
-    pub fn burn_request_exists(env: Env, request_id: String) -> bool {
-        storage::burn_request_exists(&env, &request_id)
+    /// Get a clawback record by legal reference
+    pub fn get_clawback(env: Env, legal_ref: String) -> Result<ClawbackRecord, BOBTError> {
+        storage::get_clawback(&env, &legal_ref).ok_or(BOBTError::RequestNotFound)
     }
 
-    // =========================================================================
-    // ACCESS CONTROL (OWNER)
-    // =========================================================================
-
-    /// Grant a role to an account (OWNER only)
+    /// Reverse a mint whose bank deposit proved fraudulent - BLACKLISTER_ROLE required
+    ///
+    /// Burns up to the minted amount from the original recipient without the
+    /// holder's signature and marks the request as reversed. If the recipient
+    /// already moved the funds on, the shortfall (`minted - burned`) is left
+    /// in the record for follow-up with `freeze`/`clawback`. Holds the burn
+    /// undercuts are released. Works on frozen accounts and while paused.
     ///
     /// # Arguments
-    /// * `admin` - The owner address (must authorize)
-    /// * `role` - The role symbol (minter, pauser, blklst, rescuer)
-    /// * `account` - The account to grant the role to
-    pub fn grant_role(
+    /// * `blacklister` - Address with BLACKLISTER_ROLE (must authorize)
+    /// * `request_id` - The request ID of the original mint
+    /// * `reason` - Why the mint is reversed
+    ///
+    /// # Returns
+    /// The amount burned
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks BLACKLISTER_ROLE
+    /// * `InvalidRequestId` - If request_id is empty or too long
+    /// * `RequestNotFound` - If no mint request exists for request_id
+    /// * `RequestAlreadyExists` - If the mint was already reversed
+    pub fn reverse_mint(
         env: Env,
-        admin: Address,
-        role: Symbol,
-        account: Address,
-    ) -> Result<(), BOBTError> {
-        admin.require_auth();
+        blacklister: Address,
+        request_id: String,
+        reason: String,
+    ) -> Result<i128, BOBTError> {
+        blacklister.require_auth();
 
-        require_owner(&env, &admin)?;
+        require_role(&env, &ROLE_BLACKLISTER, &blacklister)?;
+
+        validate_request_id(&request_id)?;
+        let request =
+            storage::get_mint_request(&env, &request_id).ok_or(BOBTError::RequestNotFound)?;
+        if storage::mint_reversal_exists(&env, &request_id) {
+            return Err(BOBTError::RequestAlreadyExists);
+        }
+
+        let balance = get_balance(&env, &request.to);
+        let burned = balance.min(request.amount);
+        if burned > 0 {
+            let new_balance = balance.checked_sub(burned).ok_or(BOBTError::OverflowError)?;
+            release_holds_over_balance(&env, &request.to, new_balance, &blacklister);
+            set_balance(&env, &request.to, new_balance);
+
+            let supply = get_total_supply(&env);
+            let new_supply = supply.checked_sub(burned).ok_or(BOBTError::OverflowError)?;
+            set_total_supply(&env, new_supply);
+
+            emit_clawback(&env, &blacklister, &request.to, burned);
+        }
+
+        let reversal = MintReversal {
+            to: request.to.clone(),
+            minted: request.amount,
+            burned,
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+            blacklister: blacklister.clone(),
+            reason: reason.clone(),
+        };
+        storage::store_mint_reversal(&env, &request_id, &reversal);
+
+        emit_mint_reversed(
+            &env,
+            &request.to,
+            &request_id,
+            request.amount,
+            burned,
+            &reason,
+            &blacklister,
+        );
+        extend_instance_ttl(&env);
+
+        Ok(burned)
+    }
+
+    /// Get the reversal record of a mint request
+    pub fn get_mint_reversal(env: Env, request_id: String) -> Result<MintReversal, BOBTError> {
+        storage::get_mint_reversal(&env, &request_id).ok_or(BOBTError::RequestNotFound)
+    }
+
+    // =========================================================================
+    // BALANCE HOLDS (HOLD_OPERATOR_ROLE)
+    // =========================================================================
+
+    /// Put part of an account's balance on hold (HOLD_OPERATOR_ROLE required)
+    ///
+    /// Held funds stay in the account but cannot be transferred or burned
+    /// until the hold is released, executed or expires.
+    ///
+    /// # Arguments
+    /// * `operator` - Address with HOLD_OPERATOR_ROLE (must authorize)
+    /// * `account` - The account whose funds are held
+    /// * `amount` - The amount to hold
+    /// * `hold_id` - Unique hold identifier
+    /// * `expiration_ledger` - Last ledger the hold applies to (None = no expiry)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks HOLD_OPERATOR_ROLE
+    /// * `InvalidRequestId` - If hold_id is empty or too long
+    /// * `RequestAlreadyExists` - If the hold ID is already in use
+    /// * `InvalidExpiration` - If the expiration ledger is in the past
+    /// * `TooManyHolds` - If the account already has `MAX_HOLDS_PER_ACCOUNT` holds
+    /// * `InsufficientBalance` - If the balance is below `amount`
+    /// * `FundsOnHold` - If the unheld balance is below `amount`
+    pub fn place_hold(
+        env: Env,
+        operator: Address,
+        account: Address,
+        amount: i128,
+        hold_id: String,
+        expiration_ledger: Option<u32>,
+    ) -> Result<(), BOBTError> {
+        operator.require_auth();
+
+        require_role(&env, &ROLE_HOLD_OPERATOR, &operator)?;
+        validate_amount(amount)?;
+        validate_request_id(&hold_id)?;
+
+        if storage::get_hold(&env, &hold_id).is_some() {
+            return Err(BOBTError::RequestAlreadyExists);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        if matches!(expiration_ledger, Some(expiration) if expiration < current_ledger) {
+            return Err(BOBTError::InvalidExpiration);
+        }
+
+        storage::prune_expired_holds(&env, &account);
+        if storage::get_account_holds(&env, &account).len() >= MAX_HOLDS_PER_ACCOUNT {
+            return Err(BOBTError::TooManyHolds);
+        }
+
+        let balance = get_balance(&env, &account);
+        if balance < amount {
+            return Err(BOBTError::InsufficientBalance);
+        }
+        require_spendable(&env, &account, balance, amount)?;
+
+        let hold = Hold {
+            account: account.clone(),
+            amount,
+            operator: operator.clone(),
+            ledger: current_ledger,
+            expiration_ledger,
+        };
+        storage::place_hold(&env, &hold_id, &hold);
+
+        emit_hold_placed(&env, &account, &hold_id, amount, expiration_ledger, &operator);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Release a hold, returning the funds to the spendable balance
+    /// (HOLD_OPERATOR_ROLE required)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks HOLD_OPERATOR_ROLE
+    /// * `RequestNotFound` - If no hold exists with this ID
+    pub fn release_hold(env: Env, operator: Address, hold_id: String) -> Result<(), BOBTError> {
+        operator.require_auth();
+
+        require_role(&env, &ROLE_HOLD_OPERATOR, &operator)?;
+
+        let hold = storage::get_hold(&env, &hold_id).ok_or(BOBTError::RequestNotFound)?;
+        storage::remove_hold(&env, &hold_id, &hold.account);
+
+        emit_hold_released(&env, &hold.account, &hold_id, hold.amount, &operator);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Pay held funds out to `to` and close the hold (HOLD_OPERATOR_ROLE required)
+    ///
+    /// The full hold amount is paid; no transfer fee is charged.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks HOLD_OPERATOR_ROLE
+    /// * `RequestNotFound` - If no hold exists with this ID
+    /// * `Expired` - If the hold has expired
+    /// * `ContractPaused` - If contract is paused
+    /// * `AccountFrozen` - If either account is frozen
+    pub fn execute_hold(
+        env: Env,
+        operator: Address,
+        hold_id: String,
+        to: Address,
+    ) -> Result<(), BOBTError> {
+        operator.require_auth();
+
+        require_role(&env, &ROLE_HOLD_OPERATOR, &operator)?;
+
+        let hold = storage::get_hold(&env, &hold_id).ok_or(BOBTError::RequestNotFound)?;
+        if !storage::is_hold_active(&env, &hold) {
+            return Err(BOBTError::Expired);
+        }
+
+        require_not_paused(&env, PAUSE_TRANSFER)?;
+        require_not_frozen(&env, &hold.account)?;
+        require_not_frozen(&env, &to)?;
+
+        // Close the hold first so the transfer may spend the released funds
+        storage::remove_hold(&env, &hold_id, &hold.account);
+        transfer_internal_fee_free(&env, &hold.account, &to, hold.amount)?;
+
+        emit_hold_executed(&env, &hold.account, &hold_id, &to, hold.amount, &operator);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get a hold by ID
+    pub fn get_hold(env: Env, hold_id: String) -> Result<Hold, BOBTError> {
+        storage::get_hold(&env, &hold_id).ok_or(BOBTError::RequestNotFound)
+    }
+
+    /// Get the IDs of the holds on an account (expired holds included until pruned)
+    pub fn account_holds(env: Env, account: Address) -> Vec<String> {
+        storage::get_account_holds(&env, &account)
+    }
+
+    /// Get the amount held on an account by active holds
+    pub fn held_balance(env: Env, account: Address) -> i128 {
+        storage::get_held_balance(&env, &account)
+    }
+
+    /// Get the balance an account can transfer or burn (balance minus active holds)
+    pub fn spendable_balance(env: Env, account: Address) -> i128 {
+        storage::get_spendable_balance(&env, &account)
+    }
+
+    // =========================================================================
+    // KYC OPERATIONS (KYC_OFFICER_ROLE)
+    // =========================================================================
+
+    /// Set the KYC level of an account (KYC_OFFICER_ROLE required)
+    ///
+    /// Only enforced while `require_whitelist` is enabled in the config.
+    /// Setting `KycLevel::None` revokes the approval.
+    pub fn set_kyc_level(
+        env: Env,
+        officer: Address,
+        account: Address,
+        level: KycLevel,
+    ) -> Result<(), BOBTError> {
+        officer.require_auth();
+
+        require_role(&env, &ROLE_KYC_OFFICER, &officer)?;
+
+        storage::set_kyc_level(&env, &account, level);
+
+        emit_kyc_updated(&env, &account, level, &officer);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the KYC level of an account
+    pub fn kyc_level(env: Env, account: Address) -> KycLevel {
+        storage::get_kyc_level(&env, &account)
+    }
+
+    /// Set the balance and transfer limits for a KYC level (OWNER only)
+    ///
+    /// # Errors
+    /// * `InvalidConfig` - If level is `None` or limits are not positive
+    pub fn set_tier_limits(
+        env: Env,
+        owner: Address,
+        level: KycLevel,
+        limits: TierLimits,
+    ) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+
+        if level == KycLevel::None || limits.max_balance <= 0 || limits.max_transfer <= 0 {
+            return Err(BOBTError::InvalidConfig);
+        }
+
+        storage::set_tier_limits(&env, level, &limits);
+
+        emit_tier_updated(&env, level, &limits, &owner);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the balance and transfer limits for a KYC level
+    pub fn tier_limits(env: Env, level: KycLevel) -> TierLimits {
+        storage::get_tier_limits(&env, level)
+    }
+
+    // =========================================================================
+    // RESCUE OPERATIONS (RESCUER_ROLE)
+    // =========================================================================
+
+    /// Rescue tokens sent to the contract by mistake (RESCUER_ROLE required)
+    ///
+    /// Cannot rescue BOBT tokens (the contract's own token).
+    pub fn rescue_tokens(
+        env: Env,
+        rescuer: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), BOBTError> {
+        rescuer.require_auth();
+
+        require_role(&env, &ROLE_RESCUER, &rescuer)?;
+
+        // Cannot rescue own token
+        if token == env.current_contract_address() {
+            return Err(BOBTError::CannotRescueOwnToken);
+        }
+
+        // Transfer the foreign token
+        let token_client = TokenClient::new(&env, &token);
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        emit_rescue(&env, &token, &to, amount, &rescuer);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // REQUEST TRACKING (AUDIT TRAIL)
+    // =========================================================================
+
+    /// Get a mint request by ID
+    pub fn get_mint_request(env: Env, request_id: String) -> Result<MintRequest, BOBTError> {
+        storage::get_mint_request(&env, &request_id).ok_or(BOBTError::RequestNotFound)
+    }
+
+    /// Get a burn request by ID
+    pub fn get_burn_request(env: Env, request_id: String) -> Result<BurnRequest, BOBTError> {
+        storage::get_burn_request(&env, &request_id).ok_or(BOBTError::RequestNotFound)
+    }
+
+    /// Check if a mint request exists
+    pub fn mint_request_exists(env: Env, request_id: String) -> bool {
+        storage::mint_request_exists(&env, &request_id)
+    }
+
+    /// Check if a burn request exists
+    pub fn burn_request_exists(env: Env, request_id: String) -> bool {
+        storage::burn_request_exists(&env, &request_id)
+    }
+
+    /// Keep used request ID buckets `start..start + limit` alive
+    ///
+    /// Anyone may call this; it only extends storage TTLs. A bucket is only
+    /// extended when a request ID lands in it, so a keeper must sweep all
+    /// `REQUEST_ID_BUCKETS` buckets, `MAX_BUCKETS_PER_EXTEND` per call, at
+    /// least once per maximum entry TTL or idle buckets can be archived.
+    pub fn extend_request_id_buckets(env: Env, start: u32, limit: u32) {
+        let end = start.saturating_add(limit.min(MAX_BUCKETS_PER_EXTEND));
+        storage::extend_request_id_buckets(&env, start, end);
+    }
+
+    /// Number of mint requests in the history
+    ///
+    /// Requests stored before the history indexes were introduced are only
+    /// counted and listed once added with `backfill_requests`.
+    pub fn mint_request_count(env: Env) -> u32 {
+        storage::get_mint_request_count(&env)
+    }
+
+    /// Number of burn requests in the history
+    pub fn burn_request_count(env: Env) -> u32 {
+        storage::get_burn_request_count(&env)
+    }
+
+    /// List mint requests in issuance order, one page at a time
+    ///
+    /// Returns requests `start..start + limit` (at most `MAX_PAGE_SIZE`).
+    pub fn list_mint_requests(env: Env, start: u32, limit: u32) -> Vec<MintRequest> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(storage::get_mint_request_count(&env));
+
+        let mut requests = Vec::new(&env);
+        for sequence in start..end {
+            if let Some(request) = storage::get_mint_request_at(&env, sequence) {
+                requests.push_back(request);
+            }
+        }
+        requests
+    }
+
+    /// List burn requests in order, one page at a time
+    ///
+    /// Returns requests `start..start + limit` (at most `MAX_PAGE_SIZE`).
+    pub fn list_burn_requests(env: Env, start: u32, limit: u32) -> Vec<BurnRequest> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(storage::get_burn_request_count(&env));
+
+        let mut requests = Vec::new(&env);
+        for sequence in start..end {
+            if let Some(request) = storage::get_burn_request_at(&env, sequence) {
+                requests.push_back(request);
+            }
+        }
+        requests
+    }
+
+    /// Number of requests in an account's history
+    pub fn request_count_for(env: Env, account: Address) -> u32 {
+        storage::get_account_request_count(&env, &account)
+    }
+
+    /// List the mint and burn requests involving an account, oldest first
+    ///
+    /// Mints are listed for the recipient and the minter, burns for the
+    /// holder and the operator. Returns entries `start..start + limit`
+    /// (at most `MAX_PAGE_SIZE`).
+    pub fn list_requests_for(
+        env: Env,
+        account: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<RequestRef> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(storage::get_account_request_count(&env, &account));
+
+        let mut entries = Vec::new(&env);
+        for index in start..end {
+            if let Some(entry) = storage::get_account_request_at(&env, &account, index) {
+                entries.push_back(entry);
+            }
+        }
+        entries
+    }
+
+    // =========================================================================
+    // ACCESS CONTROL (OWNER)
+    // =========================================================================
+
+    /// Grant a role to an account (OWNER or the role's admin role)
+    ///
+    /// # Arguments
+    /// * `admin` - The owner or a holder of the role's admin role (must authorize)
+    /// * `role` - The role symbol (minter, mstrmint, pauser, blklst, rescuer, kyc,
+    ///   compliance, holdop, attestor)
+    /// * `account` - The account to grant the role to
+    /// * `expiration_ledger` - Optional last ledger at which the grant is valid
+    ///
+    /// Re-granting a role replaces its previous expiration.
+    ///
+    /// # Errors
+    /// * `InvalidExpiration` - If `expiration_ledger` is in the past
+    /// * `TooManyRoleMembers` - If the role already has `MAX_ROLE_MEMBERS` members
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        role: Symbol,
+        account: Address,
+        expiration_ledger: Option<u32>,
+    ) -> Result<(), BOBTError> {
+        admin.require_auth();
+
+        // Map string role to internal symbol
+        let role_symbol = Self::map_role(&env, &role)?;
+        require_role_admin(&env, &role_symbol, &admin)?;
+
+        if let Some(expiration) = expiration_ledger {
+            if expiration < env.ledger().sequence() {
+                return Err(BOBTError::InvalidExpiration);
+            }
+        }
+
+        storage::prune_expired_role_members(&env, &role_symbol);
+        if !has_role(&env, &role_symbol, &account)
+            && storage::get_role_members(&env, &role_symbol).len() >= MAX_ROLE_MEMBERS
+        {
+            return Err(BOBTError::TooManyRoleMembers);
+        }
+        grant_role(&env, &role_symbol, &account, expiration_ledger);
+
+        emit_role_granted(&env, &role, &account, &admin, expiration_ledger);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Revoke a role from an account (OWNER or the role's admin role)
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        role: Symbol,
+        account: Address,
+    ) -> Result<(), BOBTError> {
+        admin.require_auth();
 
-        // Map string role to internal symbol
         let role_symbol = Self::map_role(&env, &role)?;
-        grant_role(&env, &role_symbol, &account);
+        require_role_admin(&env, &role_symbol, &admin)?;
+
+        revoke_role(&env, &role_symbol, &account);
+
+        emit_role_revoked(&env, &role, &account, &admin);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Check if an account has a specific role (expired grants count as absent)
+    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
+        if let Ok(role_symbol) = Self::map_role(&env, &role) {
+            has_role(&env, &role_symbol, &account)
+        } else {
+            false
+        }
+    }
+
+    /// Get the last ledger at which an account's role grant is valid
+    ///
+    /// Returns `None` for permanent grants and for accounts without the grant.
+    pub fn role_expiration(env: Env, role: Symbol, account: Address) -> Option<u32> {
+        let role_symbol = Self::map_role(&env, &role).ok()?;
+        storage::get_role_expiration(&env, &role_symbol, &account)
+    }
+
+    /// List every account actively holding a role
+    pub fn get_role_members(env: Env, role: Symbol) -> Result<Vec<Address>, BOBTError> {
+        let role_symbol = Self::map_role(&env, &role)?;
+        Ok(storage::get_active_role_members(&env, &role_symbol))
+    }
+
+    /// Get the number of accounts actively holding a role
+    pub fn role_member_count(env: Env, role: Symbol) -> Result<u32, BOBTError> {
+        let role_symbol = Self::map_role(&env, &role)?;
+        Ok(storage::get_active_role_members(&env, &role_symbol).len())
+    }
+
+    /// Get the admin role that manages a role (the owner role by default)
+    pub fn get_role_admin(env: Env, role: Symbol) -> Result<Symbol, BOBTError> {
+        let role_symbol = Self::map_role(&env, &role)?;
+        Ok(Self::role_name(&env, &storage::get_role_admin(&env, &role_symbol)))
+    }
+
+    /// Set the admin role that manages a role (OWNER only)
+    ///
+    /// Holders of `admin_role` can then grant and revoke `role`. The owner
+    /// always keeps that ability. The owner role itself cannot be delegated.
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (must authorize)
+    /// * `role` - The managed role (e.g. blklst)
+    /// * `admin_role` - The role allowed to manage it (e.g. compliance)
+    pub fn set_role_admin(
+        env: Env,
+        owner: Address,
+        role: Symbol,
+        admin_role: Symbol,
+    ) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+
+        let role_symbol = Self::map_role(&env, &role)?;
+        let admin_symbol = Self::map_role(&env, &admin_role)?;
+        if role_symbol == ROLE_OWNER {
+            return Err(BOBTError::InvalidRole);
+        }
+
+        let previous = Self::role_name(&env, &storage::get_role_admin(&env, &role_symbol));
+        storage::set_role_admin(&env, &role_symbol, &admin_symbol);
+
+        emit_role_admin_changed(&env, &role, &previous, &admin_role);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the contract owner address
+    ///
+    /// # Errors
+    /// * `OwnershipRenounced` - If the owner has renounced ownership
+    pub fn owner(env: Env) -> Result<Address, BOBTError> {
+        require_initialized(&env)?;
+        get_owner(&env).ok_or(BOBTError::OwnershipRenounced)
+    }
+
+    /// Get the pending ownership offer, if any
+    pub fn pending_owner(env: Env) -> Option<PendingOwner> {
+        storage::get_pending_owner(&env)
+    }
+
+    /// Offer ownership to a new owner (OWNER only)
+    ///
+    /// Ownership only moves once `new_owner` calls `accept_ownership`.
+    /// A new proposal replaces any previous pending offer.
+    ///
+    /// # Arguments
+    /// * `owner` - The current owner (must authorize)
+    /// * `new_owner` - The proposed owner
+    /// * `expiration_ledger` - Optional last ledger at which the offer can be accepted
+    ///
+    /// # Errors
+    /// * `InvalidNewOwner` - If `new_owner` is the current owner
+    /// * `InvalidExpiration` - If `expiration_ledger` is already in the past
+    pub fn propose_ownership(
+        env: Env,
+        owner: Address,
+        new_owner: Address,
+        expiration_ledger: Option<u32>,
+    ) -> Result<(), BOBTError> {
+        owner.require_auth();
 
-        emit_role_granted(&env, &role, &account, &admin);
+        require_owner(&env, &owner)?;
+
+        if new_owner == owner {
+            return Err(BOBTError::InvalidNewOwner);
+        }
+        if let Some(expiration) = expiration_ledger {
+            if expiration < env.ledger().sequence() {
+                return Err(BOBTError::InvalidExpiration);
+            }
+        }
+
+        storage::set_pending_owner(
+            &env,
+            &PendingOwner {
+                new_owner: new_owner.clone(),
+                expiration_ledger,
+            },
+        );
+
+        emit_ownership_proposed(&env, &owner, &new_owner, expiration_ledger);
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Revoke a role from an account (OWNER only)
-    pub fn revoke_role(
+    /// Accept a pending ownership offer (proposed owner must authorize)
+    ///
+    /// # Errors
+    /// * `NoPendingOwner` - If there is no pending offer
+    /// * `Unauthorized` - If caller is not the proposed owner
+    /// * `Expired` - If the offer has expired
+    pub fn accept_ownership(env: Env, new_owner: Address) -> Result<(), BOBTError> {
+        new_owner.require_auth();
+
+        let pending = storage::get_pending_owner(&env).ok_or(BOBTError::NoPendingOwner)?;
+        if pending.new_owner != new_owner {
+            return Err(BOBTError::Unauthorized);
+        }
+        if let Some(expiration) = pending.expiration_ledger {
+            if expiration < env.ledger().sequence() {
+                return Err(BOBTError::Expired);
+            }
+        }
+
+        let previous_owner = get_owner(&env).ok_or(BOBTError::OwnershipRenounced)?;
+
+        // Move owner role
+        revoke_role(&env, &ROLE_OWNER, &previous_owner);
+        grant_role(&env, &ROLE_OWNER, &new_owner, None);
+
+        // Update owner in storage
+        set_owner(&env, &new_owner);
+        storage::remove_pending_owner(&env);
+
+        emit_ownership_transferred(&env, &previous_owner, &new_owner);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Cancel a pending ownership offer (OWNER only)
+    ///
+    /// # Errors
+    /// * `NoPendingOwner` - If there is no pending offer
+    pub fn cancel_ownership_transfer(env: Env, owner: Address) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+
+        let pending = storage::get_pending_owner(&env).ok_or(BOBTError::NoPendingOwner)?;
+        storage::remove_pending_owner(&env);
+
+        emit_ownership_cancelled(&env, &owner, &pending.new_owner);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Permanently give up ownership (OWNER only)
+    ///
+    /// Owner-only operations (role grants, configuration, upgrades) become
+    /// unavailable forever. Any pending offer is discarded.
+    pub fn renounce_ownership(env: Env, owner: Address) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+
+        revoke_role(&env, &ROLE_OWNER, &owner);
+        storage::remove_owner(&env);
+        storage::remove_pending_owner(&env);
+
+        emit_ownership_renounced(&env, &owner);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // CONFIGURATION (OWNER)
+    // =========================================================================
+
+    /// Get the current contract configuration
+    pub fn get_config(env: Env) -> ContractConfig {
+        storage::get_config(&env)
+    }
+
+    /// Update the contract configuration (OWNER only)
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (must authorize)
+    /// * `config` - The new per-operation limits and whitelist mode
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidConfig` - If any limit is out of range
+    pub fn set_config(env: Env, owner: Address, config: ContractConfig) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+        validate_config(&config)?;
+
+        storage::set_config(&env, &config);
+
+        emit_config_updated(&env, &owner, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // TRANSFER FEES (OWNER)
+    // =========================================================================
+
+    /// Get the transfer fee configuration (None when fees are disabled)
+    pub fn fee_config(env: Env) -> Option<FeeConfig> {
+        storage::get_fee_config(&env)
+    }
+
+    /// Configure or disable the transfer fee (OWNER only)
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (must authorize)
+    /// * `config` - The fee rate, bounds and collector, or None to disable
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidConfig` - If the rate exceeds `MAX_FEE_BPS` or the bounds are invalid
+    /// * `AccountFrozen` - If the collector is frozen
+    /// * `AccountNotApproved` - If the collector lacks KYC in permissioned mode
+    pub fn set_fee_config(
         env: Env,
-        admin: Address,
-        role: Symbol,
+        owner: Address,
+        config: Option<FeeConfig>,
+    ) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+        if let Some(config) = &config {
+            validate_fee_config(config)?;
+            let balance = get_balance(&env, &config.collector);
+            require_fee_collector(&env, &config.collector, balance)?;
+        }
+
+        storage::set_fee_config(&env, &config);
+
+        emit_fee_config_updated(&env, &owner, &config);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Exempt an account from transfer fees, or remove its exemption (OWNER only)
+    ///
+    /// Transfers are fee-free when either side is exempt, e.g. the treasury,
+    /// ramp service or listed merchants.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    pub fn set_fee_exempt(
+        env: Env,
+        owner: Address,
         account: Address,
+        exempt: bool,
     ) -> Result<(), BOBTError> {
-        admin.require_auth();
+        owner.require_auth();
 
-        require_owner(&env, &admin)?;
+        require_owner(&env, &owner)?;
 
-        let role_symbol = Self::map_role(&env, &role)?;
-        revoke_role(&env, &role_symbol, &account);
+        storage::set_fee_exempt(&env, &account, exempt);
 
-        emit_role_revoked(&env, &role, &account, &admin);
+        emit_fee_exemption(&env, &account, exempt, &owner);
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Check if an account has a specific role
-    pub fn has_role(env: Env, role: Symbol, account: Address) -> bool {
-        if let Ok(role_symbol) = Self::map_role(&env, &role) {
-            has_role(&env, &role_symbol, &account)
-        } else {
-            false
+    /// Check if an account is exempt from transfer fees
+    pub fn is_fee_exempt(env: Env, account: Address) -> bool {
+        storage::is_fee_exempt(&env, &account)
+    }
+
+    /// Quote the fee charged when `from` transfers `amount` to `to`
+    ///
+    /// The recipient receives `amount` minus the returned fee.
+    pub fn quote_transfer_fee(env: Env, from: Address, to: Address, amount: i128) -> i128 {
+        if amount <= 0 {
+            return 0;
         }
+        transfer_fee(&env, &from, &to, amount).map_or(0, |(_, fee)| fee)
     }
 
-    /// Get the contract owner address
-    pub fn owner(env: Env) -> Result<Address, BOBTError> {
-        get_owner(&env).ok_or(BOBTError::NotInitialized)
+    // =========================================================================
+    // PROOF OF RESERVES (ATTESTOR)
+    // =========================================================================
+
+    /// Get the proof-of-reserves configuration (None when the gate is off)
+    pub fn reserve_config(env: Env) -> Option<ReserveConfig> {
+        storage::get_reserve_config(&env)
     }
 
-    /// Transfer ownership to a new owner (OWNER only)
-    pub fn transfer_ownership(
+    /// Enable or disable the proof-of-reserves gate on minting (OWNER only)
+    ///
+    /// While enabled, every mint requires an attestation no older than
+    /// `max_age` seconds whose reserves cover the total supply after the mint.
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (must authorize)
+    /// * `config` - The maximum attestation age, or None to disable
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidConfig` - If `max_age` is zero
+    pub fn set_reserve_config(
         env: Env,
-        current_owner: Address,
-        new_owner: Address,
+        owner: Address,
+        config: Option<ReserveConfig>,
     ) -> Result<(), BOBTError> {
-        current_owner.require_auth();
+        owner.require_auth();
 
-        require_owner(&env, &current_owner)?;
+        require_owner(&env, &owner)?;
+        if let Some(config) = &config {
+            if config.max_age == 0 {
+                return Err(BOBTError::InvalidConfig);
+            }
+        }
 
-        // Revoke owner role from current owner
-        revoke_role(&env, &ROLE_OWNER, &current_owner);
+        storage::set_reserve_config(&env, &config);
 
-        // Grant owner role to new owner
-        grant_role(&env, &ROLE_OWNER, &new_owner);
+        emit_reserve_config_updated(&env, &owner, &config);
+        extend_instance_ttl(&env);
 
-        // Update owner in storage
-        set_owner(&env, &new_owner);
+        Ok(())
+    }
+
+    /// Post a reserve attestation (ATTESTOR role)
+    ///
+    /// Replaces the previous attestation. Attestations must be posted in
+    /// `as_of` order so an older report cannot be replayed over a newer one.
+    ///
+    /// # Arguments
+    /// * `attestor` - The attestor address (must authorize)
+    /// * `reserves` - Fiat reserves backing the token (in stroops)
+    /// * `as_of` - Timestamp the reserves were measured at
+    /// * `report_hash` - Hash of the auditor's report
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller lacks ATTESTOR role
+    /// * `NegativeAmount` - If reserves are negative
+    /// * `ReservesInsufficient` - If `as_of` is in the future, not newer than
+    ///   the latest attestation, or already older than the gate's `max_age`
+    pub fn post_reserve_attestation(
+        env: Env,
+        attestor: Address,
+        reserves: i128,
+        as_of: u64,
+        report_hash: BytesN<32>,
+    ) -> Result<(), BOBTError> {
+        attestor.require_auth();
+
+        require_role(&env, &ROLE_ATTESTOR, &attestor)?;
+        if reserves < 0 {
+            return Err(BOBTError::NegativeAmount);
+        }
+        if as_of > env.ledger().timestamp() {
+            return Err(BOBTError::ReservesInsufficient);
+        }
+        if let Some(latest) = storage::get_reserve_attestation(&env) {
+            if as_of <= latest.as_of {
+                return Err(BOBTError::ReservesInsufficient);
+            }
+        }
+        if let Some(config) = storage::get_reserve_config(&env) {
+            if is_attestation_stale(&env, &config, as_of) {
+                return Err(BOBTError::ReservesInsufficient);
+            }
+        }
 
-        emit_ownership_transferred(&env, &current_owner, &new_owner);
+        let attestation = ReserveAttestation {
+            reserves,
+            as_of,
+            report_hash,
+            attestor,
+            ledger: env.ledger().sequence(),
+        };
+        storage::set_reserve_attestation(&env, &attestation);
+
+        emit_reserve_attested(&env, &attestation);
         extend_instance_ttl(&env);
 
         Ok(())
     }
 
+    /// Get the latest reserve attestation
+    pub fn reserve_attestation(env: Env) -> Option<ReserveAttestation> {
+        storage::get_reserve_attestation(&env)
+    }
+
+    /// Latest attested reserves over total supply, in basis points
+    ///
+    /// 10_000 means fully backed. Returns None when nothing has been attested
+    /// or the supply is zero. Freshness is not considered; compare the
+    /// attestation's `as_of` against `reserve_config` for that.
+    pub fn collateralization_ratio(env: Env) -> Option<i128> {
+        let attestation = storage::get_reserve_attestation(&env)?;
+        let supply = get_total_supply(&env);
+        if supply == 0 {
+            return None;
+        }
+        attestation.reserves.checked_mul(BPS_DENOMINATOR).map(|scaled| scaled / supply)
+    }
+
     // =========================================================================
     // UPGRADE
     // =========================================================================
 
-    /// Upgrade the contract (OWNER only)
+    /// Schedule an upgrade behind the timelock (OWNER only)
+    ///
+    /// The upgrade can be executed once `upgrade_delay` ledgers have passed,
+    /// giving holders notice of the new code before it goes live.
     ///
     /// # Arguments
     /// * `operator` - The owner address (must authorize)
-    /// * `new_wasm_hash` - The hash of the new WASM binary
+    /// * `wasm_hash` - The hash of the new WASM binary
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `RequestAlreadyExists` - If another upgrade is pending
+    pub fn schedule_upgrade(
+        env: Env,
+        operator: Address,
+        wasm_hash: BytesN<32>,
+    ) -> Result<u32, BOBTError> {
+        operator.require_auth();
+
+        require_owner(&env, &operator)?;
+
+        if storage::get_pending_upgrade(&env).is_some() {
+            return Err(BOBTError::RequestAlreadyExists);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        let eta_ledger = current_ledger
+            .checked_add(storage::get_upgrade_delay(&env))
+            .ok_or(BOBTError::OverflowError)?;
+
+        storage::set_pending_upgrade(
+            &env,
+            &PendingUpgrade {
+                wasm_hash: wasm_hash.clone(),
+                scheduled_ledger: current_ledger,
+                eta_ledger,
+            },
+        );
+
+        emit_upgrade_scheduled(&env, &operator, &wasm_hash, eta_ledger);
+        extend_instance_ttl(&env);
+
+        Ok(eta_ledger)
+    }
+
+    /// Cancel the scheduled upgrade (OWNER only)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `RequestNotFound` - If there is no pending upgrade
+    pub fn cancel_upgrade(env: Env, operator: Address) -> Result<(), BOBTError> {
+        operator.require_auth();
+
+        require_owner(&env, &operator)?;
+
+        let pending = storage::get_pending_upgrade(&env).ok_or(BOBTError::RequestNotFound)?;
+        storage::remove_pending_upgrade(&env);
+
+        emit_upgrade_cancelled(&env, &operator, &pending.wasm_hash);
+        extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Execute the scheduled upgrade once its timelock has passed (OWNER only)
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `RequestNotFound` - If there is no pending upgrade
+    /// * `UpgradeTimelockActive` - If the delay has not yet elapsed
+    pub fn execute_upgrade(env: Env, operator: Address) -> Result<(), BOBTError> {
+        operator.require_auth();
+
+        require_owner(&env, &operator)?;
+
+        let pending = storage::get_pending_upgrade(&env).ok_or(BOBTError::RequestNotFound)?;
+        Self::apply_upgrade(&env, &operator, pending)
+    }
+
+    /// Upgrade the contract to a scheduled WASM (OWNER only)
+    ///
+    /// Kept for the Upgrader contract. Behaves like `execute_upgrade`, but
+    /// additionally checks that `new_wasm_hash` is the one that was scheduled.
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `RequestNotFound` - If there is no pending upgrade
+    /// * `InvalidWasmHash` - If the hash differs from the scheduled one
+    /// * `UpgradeTimelockActive` - If the delay has not yet elapsed
     pub fn upgrade(env: Env, operator: Address, new_wasm_hash: BytesN<32>) -> Result<(), BOBTError> {
         operator.require_auth();
 
         require_owner(&env, &operator)?;
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        let pending = storage::get_pending_upgrade(&env).ok_or(BOBTError::RequestNotFound)?;
+        if pending.wasm_hash != new_wasm_hash {
+            return Err(BOBTError::InvalidWasmHash);
+        }
+
+        Self::apply_upgrade(&env, &operator, pending)
+    }
+
+    /// Get the scheduled upgrade, if any
+    pub fn pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        storage::get_pending_upgrade(&env)
+    }
+
+    /// Get the upgrade timelock delay in ledgers
+    pub fn upgrade_delay(env: Env) -> u32 {
+        storage::get_upgrade_delay(&env)
+    }
+
+    /// Set the upgrade timelock delay (OWNER only)
+    ///
+    /// Applies to upgrades scheduled afterwards; a pending upgrade keeps the
+    /// eta it was scheduled with.
+    ///
+    /// # Arguments
+    /// * `owner` - The owner address (must authorize)
+    /// * `delay` - Delay in ledgers, between `MIN_UPGRADE_DELAY` and `MAX_UPGRADE_DELAY`
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidConfig` - If the delay is out of range
+    pub fn set_upgrade_delay(env: Env, owner: Address, delay: u32) -> Result<(), BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+
+        if !(MIN_UPGRADE_DELAY..=MAX_UPGRADE_DELAY).contains(&delay) {
+            return Err(BOBTError::InvalidConfig);
+        }
+
+        storage::set_upgrade_delay(&env, delay);
 
-        emit_upgraded(&env, &operator);
+        emit_upgrade_delay_updated(&env, &owner, delay);
+        extend_instance_ttl(&env);
 
         Ok(())
     }
 
-    /// Migrate contract state after upgrade
+    /// Migrate contract state after upgrade (OWNER must authorize)
+    ///
+    /// Runs each migration step from the stored version up to
+    /// `CONTRACT_VERSION` exactly once, emitting `migrated` per step.
+    ///
+    /// Version history:
+    /// * v2 - Legacy unauthenticated `mint` disabled (no storage changes)
+    /// * v3 - Owner seeded into the role member set
+    /// * v4 - Member sets for every role (existing grants: `backfill_role_members`)
+    /// * v5 - Freeze records (legacy freeze flags: `backfill_freezes`)
+    /// * v6 - Used request ID set (existing requests: `backfill_requests`)
     ///
-    /// This function is called after upgrade to perform any necessary migrations.
-    /// It's a no-op for initial deployment.
-    pub fn migrate(_env: Env) -> Result<(), BOBTError> {
-        // Reserved for future migrations
-        // Each version can implement specific migration logic
+    /// # Errors
+    /// * `NotInitialized` - If there is no owner
+    /// * `AlreadyMigrated` - If the contract is already current
+    /// * `MigrationFailed` - If the stored version is unknown
+    pub fn migrate(env: Env) -> Result<(), BOBTError> {
+        let owner = get_owner(&env).ok_or(BOBTError::NotInitialized)?;
+        owner.require_auth();
+
+        migration::run(&env)?;
+
+        extend_instance_ttl(&env);
+
         Ok(())
     }
 
+    /// Add role grants made before role enumeration to the member set (OWNER only)
+    ///
+    /// Pass every holder of `role` from before the upgrade, in batches of at
+    /// most `MAX_BACKFILL_BATCH`. Candidates not holding the role or already
+    /// listed are skipped, so batches can be resumed or repeated.
+    ///
+    /// # Returns
+    /// The number of members added
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidRole` - If the role is unknown
+    /// * `InvalidBatchSize` - If more than `MAX_BACKFILL_BATCH` accounts are passed
+    pub fn backfill_role_members(
+        env: Env,
+        owner: Address,
+        role: Symbol,
+        accounts: Vec<Address>,
+    ) -> Result<u32, BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+        let role_symbol = Self::map_role(&env, &role)?;
+        if accounts.len() > MAX_BACKFILL_BATCH {
+            return Err(BOBTError::InvalidBatchSize);
+        }
+
+        let added = migration::backfill_role_members(&env, &role_symbol, &accounts);
+
+        emit_backfilled(&env, &owner, Symbol::new(&env, "role_members"), added);
+        extend_instance_ttl(&env);
+
+        Ok(added)
+    }
+
+    /// Convert legacy freeze flags into freeze records (OWNER only)
+    ///
+    /// Pass every account frozen before freeze records existed, in batches of
+    /// at most `MAX_BACKFILL_BATCH`. Each gets a permanent `Legacy` freeze
+    /// attributed to the owner and is listed in `frozen_accounts`. Candidates
+    /// without the flag or already recorded are skipped.
+    ///
+    /// # Returns
+    /// The number of freeze records created
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidBatchSize` - If more than `MAX_BACKFILL_BATCH` accounts are passed
+    pub fn backfill_freezes(
+        env: Env,
+        owner: Address,
+        accounts: Vec<Address>,
+    ) -> Result<u32, BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+        if accounts.len() > MAX_BACKFILL_BATCH {
+            return Err(BOBTError::InvalidBatchSize);
+        }
+
+        let added = migration::backfill_freezes(&env, &owner, &accounts);
+
+        emit_backfilled(&env, &owner, Symbol::new(&env, "freezes"), added);
+        extend_instance_ttl(&env);
+
+        Ok(added)
+    }
+
+    /// Index requests recorded before the history indexes (OWNER only)
+    ///
+    /// Pass every mint and burn request ID from before the upgrade, e.g.
+    /// from the `mint`/`burn` event history, in batches of at most
+    /// `MAX_REQUEST_BACKFILL_BATCH`. Each request is added to the used ID
+    /// set and appended to the mint or burn history and the histories of
+    /// the accounts involved. Candidates without a stored record or already
+    /// indexed are skipped, so batches can be resumed or repeated.
+    ///
+    /// # Returns
+    /// The number of requests indexed
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the owner
+    /// * `InvalidBatchSize` - If more than `MAX_REQUEST_BACKFILL_BATCH` IDs are passed
+    pub fn backfill_requests(
+        env: Env,
+        owner: Address,
+        request_ids: Vec<String>,
+    ) -> Result<u32, BOBTError> {
+        owner.require_auth();
+
+        require_owner(&env, &owner)?;
+        if request_ids.len() > MAX_REQUEST_BACKFILL_BATCH {
+            return Err(BOBTError::InvalidBatchSize);
+        }
+
+        let added = migration::backfill_requests(&env, &request_ids);
+
+        emit_backfilled(&env, &owner, Symbol::new(&env, "requests"), added);
+        extend_instance_ttl(&env);
+
+        Ok(added)
+    }
+
     // =========================================================================
     // INTERNAL HELPERS
     // =========================================================================
 
+    /// Swap in a scheduled WASM once its timelock has elapsed
+    fn apply_upgrade(
+        env: &Env,
+        operator: &Address,
+        pending: PendingUpgrade,
+    ) -> Result<(), BOBTError> {
+        if env.ledger().sequence() < pending.eta_ledger {
+            return Err(BOBTError::UpgradeTimelockActive);
+        }
+
+        storage::remove_pending_upgrade(env);
+        env.deployer().update_current_contract_wasm(pending.wasm_hash.clone());
+
+        emit_upgraded(env, operator, &pending.wasm_hash);
+
+        Ok(())
+    }
+
     /// Map user-friendly role names to internal role symbols
     fn map_role(env: &Env, role: &Symbol) -> Result<Symbol, BOBTError> {
         // Compare with known role names
         let minter_sym = Symbol::new(env, "minter");
+        let master_minter_sym = Symbol::new(env, "mstrmint");
         let pauser_sym = Symbol::new(env, "pauser");
         let blklst_sym = Symbol::new(env, "blklst");
         let rescuer_sym = Symbol::new(env, "rescuer");
+        let kyc_sym = Symbol::new(env, "kyc");
+        let compliance_sym = Symbol::new(env, "compliance");
+        let hold_operator_sym = Symbol::new(env, "holdop");
+        let attestor_sym = Symbol::new(env, "attestor");
         let owner_sym = Symbol::new(env, "owner");
 
         if *role == minter_sym {
             Ok(ROLE_MINTER)
+        } else if *role == master_minter_sym {
+            Ok(ROLE_MASTER_MINTER)
         } else if *role == pauser_sym {
             Ok(ROLE_PAUSER)
         } else if *role == blklst_sym {
             Ok(ROLE_BLACKLISTER)
         } else if *role == rescuer_sym {
             Ok(ROLE_RESCUER)
+        } else if *role == kyc_sym {
+            Ok(ROLE_KYC_OFFICER)
+        } else if *role == compliance_sym {
+            Ok(ROLE_COMPLIANCE)
+        } else if *role == hold_operator_sym {
+            Ok(ROLE_HOLD_OPERATOR)
+        } else if *role == attestor_sym {
+            Ok(ROLE_ATTESTOR)
         } else if *role == owner_sym {
             Ok(ROLE_OWNER)
         } else {
             Err(BOBTError::InvalidRole)
         }
     }
+
+    /// Map internal role symbols back to user-friendly role names
+    fn role_name(env: &Env, role_symbol: &Symbol) -> Symbol {
+        let name = if *role_symbol == ROLE_MINTER {
+            "minter"
+        } else if *role_symbol == ROLE_MASTER_MINTER {
+            "mstrmint"
+        } else if *role_symbol == ROLE_PAUSER {
+            "pauser"
+        } else if *role_symbol == ROLE_BLACKLISTER {
+            "blklst"
+        } else if *role_symbol == ROLE_RESCUER {
+            "rescuer"
+        } else if *role_symbol == ROLE_KYC_OFFICER {
+            "kyc"
+        } else if *role_symbol == ROLE_COMPLIANCE {
+            "compliance"
+        } else if *role_symbol == ROLE_HOLD_OPERATOR {
+            "holdop"
+        } else if *role_symbol == ROLE_ATTESTOR {
+            "attestor"
+        } else {
+            "owner"
+        };
+        Symbol::new(env, name)
+    }
 }
@@ -10,12 +10,20 @@ use soroban_sdk::contracterror;
 /// Error codes are organized by category:
 /// - 1-10: Access Control errors
 /// - 11-20: Pausable errors
-/// - 21-30: Blocklist/Freeze errors
+/// - 21-30: Blocklist/Freeze/KYC errors
 /// - 31-50: Token operation errors
 /// - 51-60: Request tracking errors
 /// - 61-70: Rescue operation errors
 /// - 71-80: Upgrade errors
 /// - 81-90: Initialization errors
+/// - 91-100: Expiration errors
+/// - 101-110: Balance hold errors
+/// - 111-120: Signature errors
+///
+/// Integrators match on these numbers, so a code keeps its number and
+/// meaning once released, even if the contract no longer returns it.
+/// Soroban caps a contract error enum at 50 variants, so new conditions
+/// reuse an existing variant where one fits.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -39,6 +47,18 @@ pub enum BOBTError {
     /// Cannot transfer ownership to zero address
     InvalidNewOwner = 5,
 
+    /// The unauthenticated legacy `mint` entrypoint has been disabled
+    LegacyMintDisabled = 6,
+
+    /// There is no pending ownership offer
+    NoPendingOwner = 7,
+
+    /// Ownership has been renounced
+    OwnershipRenounced = 9,
+
+    /// Role already has the maximum number of members
+    TooManyRoleMembers = 10,
+
     // =========================================================================
     // PAUSABLE ERRORS (11-20)
     // =========================================================================
@@ -50,7 +70,7 @@ pub enum BOBTError {
     ContractNotPaused = 12,
 
     // =========================================================================
-    // BLOCKLIST/FREEZE ERRORS (21-30)
+    // BLOCKLIST/FREEZE/KYC ERRORS (21-30)
     // =========================================================================
 
     /// Account is frozen and cannot perform operations
@@ -65,6 +85,12 @@ pub enum BOBTError {
     /// Cannot perform operation on frozen account
     OperationOnFrozenAccount = 24,
 
+    /// Account has not been approved by a KYC officer
+    AccountNotApproved = 25,
+
+    /// Resulting balance or transfer amount exceeds the account's KYC tier limit
+    KycLimitExceeded = 26,
+
     // =========================================================================
     // TOKEN OPERATION ERRORS (31-50)
     // =========================================================================
@@ -93,14 +119,41 @@ pub enum BOBTError {
     /// Cannot transfer to self
     CannotTransferToSelf = 38,
 
+    /// Mint amount exceeds the configured per-operation maximum
+    MintLimitExceeded = 39,
+
+    /// Burn amount exceeds the configured per-operation maximum
+    BurnLimitExceeded = 40,
+
+    /// Transfer amount is below the configured minimum
+    BelowMinTransfer = 41,
+
+    /// Configuration values are out of range (limits, fees, KYC tiers,
+    /// upgrade delay)
+    InvalidConfig = 42,
+
+    /// Amount exceeds the minter's remaining mint allowance
+    MinterAllowanceExceeded = 43,
+
+    /// Batch is empty or has more than `MAX_BATCH_SIZE` entries
+    InvalidBatchSize = 44,
+
+    /// Payment memo is longer than `MAX_MEMO_LEN` bytes
+    MemoTooLong = 45,
+
+    /// Mint would push total supply above the latest attested reserves, no
+    /// attestation within the reserve gate's `max_age` exists, or a posted
+    /// attestation is future-dated, out of order or already stale
+    ReservesInsufficient = 46,
+
     // =========================================================================
     // REQUEST TRACKING ERRORS (51-60)
     // =========================================================================
 
-    /// A request with this ID already exists
+    /// A request with this ID already exists (or an upgrade is already scheduled)
     RequestAlreadyExists = 51,
 
-    /// Request not found
+    /// Request not found (or no upgrade is scheduled)
     RequestNotFound = 52,
 
     /// Invalid request ID format
@@ -126,6 +179,12 @@ pub enum BOBTError {
     /// Invalid WASM hash provided
     InvalidWasmHash = 72,
 
+    /// Scheduled upgrade is still inside its timelock
+    UpgradeTimelockActive = 74,
+
+    /// Stored state is already at the current contract version
+    AlreadyMigrated = 77,
+
     // =========================================================================
     // INITIALIZATION ERRORS (81-90)
     // =========================================================================
@@ -135,4 +194,36 @@ pub enum BOBTError {
 
     /// Contract has not been initialized
     NotInitialized = 82,
+
+    // =========================================================================
+    // EXPIRATION ERRORS (91-100)
+    // =========================================================================
+
+    /// Expiration ledger is in the past (role grants, ownership offers,
+    /// freezes, holds)
+    InvalidExpiration = 91,
+
+    /// Record has expired and can no longer be used (e.g. an ownership offer
+    /// past its expiration ledger)
+    Expired = 92,
+
+    // =========================================================================
+    // BALANCE HOLD ERRORS (101-110)
+    // =========================================================================
+
+    /// Account has reached the maximum number of active holds
+    TooManyHolds = 101,
+
+    /// Amount would spend funds that are on hold
+    FundsOnHold = 102,
+
+    // =========================================================================
+    // SIGNATURE ERRORS (111-120)
+    // =========================================================================
+
+    /// Signer cannot produce ed25519 signatures (e.g. a contract address)
+    InvalidSignature = 111,
+
+    /// Nonce does not match the signer's next nonce
+    InvalidNonce = 112,
 }
@@ -3,37 +3,205 @@
 //! This module defines all events that are emitted by the BOBT contract
 //! for off-chain monitoring and indexing.
 
-use soroban_sdk::{Address, Env, String, Symbol};
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Symbol};
+
+use crate::storage::{
+    ContractConfig, FeeConfig, FreezeRecord, KycLevel, ReserveAttestation, ReserveConfig,
+    TierLimits,
+};
 
 // =============================================================================
-// MINT/BURN EVENTS
+// SEP-41 TOKEN EVENTS
 // =============================================================================
 
+/// Emitted when tokens move between two accounts
+///
+/// Topics: ("transfer", from, to)
+/// Data: amount
+pub fn emit_transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+    let topics = (Symbol::new(env, "transfer"), from.clone(), to.clone());
+    env.events().publish(topics, amount);
+}
+
+/// Emitted instead of the plain `transfer` event when a payment carries a memo
+///
+/// Topics: ("transfer", from, to)
+/// Data: (amount, memo)
+pub fn emit_transfer_with_memo(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    memo: &Bytes,
+) {
+    let topics = (Symbol::new(env, "transfer"), from.clone(), to.clone());
+    env.events().publish(topics, (amount, memo.clone()));
+}
+
+/// Emitted when an allowance is set
+///
+/// Topics: ("approve", from, spender)
+/// Data: (amount, expiration_ledger)
+pub fn emit_approve(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    let topics = (Symbol::new(env, "approve"), from.clone(), spender.clone());
+    env.events().publish(topics, (amount, expiration_ledger));
+}
+
 /// Emitted when new tokens are minted
 ///
-/// Topics: ("mint", to, minter)
+/// Topics: ("mint", admin, to)
+/// Data: amount
+pub fn emit_mint(env: &Env, admin: &Address, to: &Address, amount: i128) {
+    let topics = (Symbol::new(env, "mint"), admin.clone(), to.clone());
+    env.events().publish(topics, amount);
+}
+
+/// Emitted when tokens are burned
+///
+/// Topics: ("burn", from)
+/// Data: amount
+pub fn emit_burn(env: &Env, from: &Address, amount: i128) {
+    let topics = (Symbol::new(env, "burn"), from.clone());
+    env.events().publish(topics, amount);
+}
+
+/// Emitted when tokens are forcibly removed from an account
+///
+/// Topics: ("clawback", admin, from)
+/// Data: amount
+pub fn emit_clawback(env: &Env, admin: &Address, from: &Address, amount: i128) {
+    let topics = (Symbol::new(env, "clawback"), admin.clone(), from.clone());
+    env.events().publish(topics, amount);
+}
+
+// =============================================================================
+// REQUEST TRACKING EVENTS
+// =============================================================================
+
+/// Emitted alongside `mint` when a tracked mint request is recorded
+///
+/// Topics: ("mint_request", to, minter)
 /// Data: (amount, request_id)
-pub fn emit_mint(env: &Env, to: &Address, amount: i128, request_id: &String, minter: &Address) {
-    let topics = (Symbol::new(env, "mint"), to.clone(), minter.clone());
+pub fn emit_mint_request(
+    env: &Env,
+    to: &Address,
+    amount: i128,
+    request_id: &String,
+    minter: &Address,
+) {
+    let topics = (Symbol::new(env, "mint_request"), to.clone(), minter.clone());
     env.events().publish(topics, (amount, request_id.clone()));
 }
 
-/// Emitted when tokens are burned
+/// Emitted when a minter reserves supply for an unsettled deposit
 ///
-/// Topics: ("burn", from, operator)
+/// Topics: ("mint_reserved", to, minter)
+/// Data: (amount, request_id, confirm_by_ledger)
+pub fn emit_mint_reserved(
+    env: &Env,
+    to: &Address,
+    amount: i128,
+    request_id: &String,
+    confirm_by_ledger: u32,
+    minter: &Address,
+) {
+    let topics = (Symbol::new(env, "mint_reserved"), to.clone(), minter.clone());
+    env.events().publish(topics, (amount, request_id.clone(), confirm_by_ledger));
+}
+
+/// Emitted when a reserved mint is discarded
+///
+/// Topics: ("mint_voided", to, minter)
 /// Data: (amount, request_id)
-pub fn emit_burn(env: &Env, from: &Address, amount: i128, request_id: &String, operator: &Address) {
-    let topics = (Symbol::new(env, "burn"), from.clone(), operator.clone());
+pub fn emit_mint_voided(
+    env: &Env,
+    to: &Address,
+    amount: i128,
+    request_id: &String,
+    minter: &Address,
+) {
+    let topics = (Symbol::new(env, "mint_voided"), to.clone(), minter.clone());
     env.events().publish(topics, (amount, request_id.clone()));
 }
 
-/// Emitted when a user burns their own tokens
+/// Emitted when a blacklister seizes funds under a legal reference
 ///
-/// Topics: ("user_burn", from)
-/// Data: amount
-pub fn emit_user_burn(env: &Env, from: &Address, amount: i128) {
-    let topics = (Symbol::new(env, "user_burn"), from.clone());
-    env.events().publish(topics, amount);
+/// Accompanied by `transfer` (moved to custody) or `clawback` (burned).
+///
+/// Topics: ("seizure", account, blacklister)
+/// Data: (amount, legal_ref, destination)
+pub fn emit_seizure(
+    env: &Env,
+    account: &Address,
+    amount: i128,
+    legal_ref: &String,
+    destination: &Option<Address>,
+    blacklister: &Address,
+) {
+    let topics = (Symbol::new(env, "seizure"), account.clone(), blacklister.clone());
+    env.events().publish(topics, (amount, legal_ref.clone(), destination.clone()));
+}
+
+/// Emitted when a mint is reversed after its deposit proved fraudulent
+///
+/// Accompanied by `clawback` when any amount could be burned.
+///
+/// Topics: ("mint_reversed", to, blacklister)
+/// Data: (request_id, minted, burned, reason)
+pub fn emit_mint_reversed(
+    env: &Env,
+    to: &Address,
+    request_id: &String,
+    minted: i128,
+    burned: i128,
+    reason: &String,
+    blacklister: &Address,
+) {
+    let topics = (Symbol::new(env, "mint_reversed"), to.clone(), blacklister.clone());
+    env.events().publish(topics, (request_id.clone(), minted, burned, reason.clone()));
+}
+
+/// Emitted alongside `burn` when a tracked burn request is recorded
+///
+/// Topics: ("burn_request", from, operator)
+/// Data: (amount, request_id)
+pub fn emit_burn_request(
+    env: &Env,
+    from: &Address,
+    amount: i128,
+    request_id: &String,
+    operator: &Address,
+) {
+    let topics = (Symbol::new(env, "burn_request"), from.clone(), operator.clone());
+    env.events().publish(topics, (amount, request_id.clone()));
+}
+
+// =============================================================================
+// MINTER ALLOWANCE EVENTS
+// =============================================================================
+
+/// Emitted when a master minter sets, raises or lowers a minter's allowance
+///
+/// Topics: ("minter_allowance", minter)
+/// Data: (allowance, master_minter)
+pub fn emit_minter_allowance(env: &Env, minter: &Address, allowance: i128, master: &Address) {
+    let topics = (Symbol::new(env, "minter_allowance"), minter.clone());
+    env.events().publish(topics, (allowance, master.clone()));
+}
+
+/// Emitted when a master minter removes a minter's allowance
+///
+/// Topics: ("minter_removed", minter)
+/// Data: master_minter
+pub fn emit_minter_removed(env: &Env, minter: &Address, master: &Address) {
+    let topics = (Symbol::new(env, "minter_removed"), minter.clone());
+    env.events().publish(topics, master.clone());
 }
 
 // =============================================================================
@@ -43,10 +211,16 @@ pub fn emit_user_burn(env: &Env, from: &Address, amount: i128) {
 /// Emitted when an account is frozen (added to blocklist)
 ///
 /// Topics: ("freeze", account)
-/// Data: blacklister
-pub fn emit_freeze(env: &Env, account: &Address, blacklister: &Address) {
+/// Data: (blacklister, reason, case_ref, expiration_ledger)
+pub fn emit_freeze(env: &Env, account: &Address, record: &FreezeRecord) {
     let topics = (Symbol::new(env, "freeze"), account.clone());
-    env.events().publish(topics, blacklister.clone());
+    let data = (
+        record.blacklister.clone(),
+        record.reason,
+        record.case_ref.clone(),
+        record.expiration_ledger,
+    );
+    env.events().publish(topics, data);
 }
 
 /// Emitted when an account is unfrozen (removed from blocklist)
@@ -58,34 +232,126 @@ pub fn emit_unfreeze(env: &Env, account: &Address, blacklister: &Address) {
     env.events().publish(topics, blacklister.clone());
 }
 
-/// Emitted when frozen funds are destroyed (clawback)
+// =============================================================================
+// BALANCE HOLD EVENTS
+// =============================================================================
+
+/// Emitted when part of an account's balance is put on hold
+///
+/// Topics: ("hold_placed", account, operator)
+/// Data: (hold_id, amount, expiration_ledger)
+pub fn emit_hold_placed(
+    env: &Env,
+    account: &Address,
+    hold_id: &String,
+    amount: i128,
+    expiration_ledger: Option<u32>,
+    operator: &Address,
+) {
+    let topics = (Symbol::new(env, "hold_placed"), account.clone(), operator.clone());
+    env.events().publish(topics, (hold_id.clone(), amount, expiration_ledger));
+}
+
+/// Emitted when a hold is released without moving funds
+///
+/// Topics: ("hold_released", account, operator)
+/// Data: (hold_id, amount)
+pub fn emit_hold_released(
+    env: &Env,
+    account: &Address,
+    hold_id: &String,
+    amount: i128,
+    operator: &Address,
+) {
+    let topics = (Symbol::new(env, "hold_released"), account.clone(), operator.clone());
+    env.events().publish(topics, (hold_id.clone(), amount));
+}
+
+/// Emitted alongside `transfer` when held funds are paid out
+///
+/// Topics: ("hold_executed", account, operator)
+/// Data: (hold_id, to, amount)
+pub fn emit_hold_executed(
+    env: &Env,
+    account: &Address,
+    hold_id: &String,
+    to: &Address,
+    amount: i128,
+    operator: &Address,
+) {
+    let topics = (Symbol::new(env, "hold_executed"), account.clone(), operator.clone());
+    env.events().publish(topics, (hold_id.clone(), to.clone(), amount));
+}
+
+// =============================================================================
+// SIGNED AUTHORIZATION EVENTS
+// =============================================================================
+
+/// Emitted alongside `transfer` when a signed transfer authorization is used
+///
+/// Topics: ("auth_used", from, relayer)
+/// Data: (nonce, fee)
+pub fn emit_authorization_used(
+    env: &Env,
+    from: &Address,
+    nonce: &BytesN<32>,
+    relayer: &Address,
+    fee: i128,
+) {
+    let topics = (Symbol::new(env, "auth_used"), from.clone(), relayer.clone());
+    env.events().publish(topics, (nonce.clone(), fee));
+}
+
+/// Emitted when a holder cancels an unused transfer authorization
+///
+/// Topics: ("auth_canceled", from)
+/// Data: nonce
+pub fn emit_authorization_canceled(env: &Env, from: &Address, nonce: &BytesN<32>) {
+    let topics = (Symbol::new(env, "auth_canceled"), from.clone());
+    env.events().publish(topics, nonce.clone());
+}
+
+// =============================================================================
+// KYC EVENTS
+// =============================================================================
+
+/// Emitted when a KYC officer changes an account's KYC level
+///
+/// Topics: ("kyc_updated", account)
+/// Data: (level, officer)
+pub fn emit_kyc_updated(env: &Env, account: &Address, level: KycLevel, officer: &Address) {
+    let topics = (Symbol::new(env, "kyc_updated"), account.clone());
+    env.events().publish(topics, (level, officer.clone()));
+}
+
+/// Emitted when the owner changes the limits of a KYC level
 ///
-/// Topics: ("clawback", account)
-/// Data: (amount, blacklister)
-pub fn emit_clawback(env: &Env, account: &Address, amount: i128, blacklister: &Address) {
-    let topics = (Symbol::new(env, "clawback"), account.clone());
-    env.events().publish(topics, (amount, blacklister.clone()));
+/// Topics: ("tier_updated", level)
+/// Data: (limits, owner)
+pub fn emit_tier_updated(env: &Env, level: KycLevel, limits: &TierLimits, owner: &Address) {
+    let topics = (Symbol::new(env, "tier_updated"), level);
+    env.events().publish(topics, (limits.clone(), owner.clone()));
 }
 
 // =============================================================================
 // PAUSE EVENTS
 // =============================================================================
 
-/// Emitted when the contract is paused
+/// Emitted once per operation class that becomes paused
 ///
-/// Topics: ("pause",)
+/// Topics: ("pause", flag)
 /// Data: pauser
-pub fn emit_pause(env: &Env, pauser: &Address) {
-    let topics = (Symbol::new(env, "pause"),);
+pub fn emit_pause(env: &Env, flag: u32, pauser: &Address) {
+    let topics = (Symbol::new(env, "pause"), flag);
     env.events().publish(topics, pauser.clone());
 }
 
-/// Emitted when the contract is unpaused
+/// Emitted once per operation class that becomes unpaused
 ///
-/// Topics: ("unpause",)
+/// Topics: ("unpause", flag)
 /// Data: pauser
-pub fn emit_unpause(env: &Env, pauser: &Address) {
-    let topics = (Symbol::new(env, "unpause"),);
+pub fn emit_unpause(env: &Env, flag: u32, pauser: &Address) {
+    let topics = (Symbol::new(env, "unpause"), flag);
     env.events().publish(topics, pauser.clone());
 }
 
@@ -109,10 +375,16 @@ pub fn emit_rescue(env: &Env, token: &Address, to: &Address, amount: i128, rescu
 /// Emitted when a role is granted to an account
 ///
 /// Topics: ("role_granted", role, account)
-/// Data: admin
-pub fn emit_role_granted(env: &Env, role: &Symbol, account: &Address, admin: &Address) {
+/// Data: (admin, expiration_ledger)
+pub fn emit_role_granted(
+    env: &Env,
+    role: &Symbol,
+    account: &Address,
+    admin: &Address,
+    expiration_ledger: Option<u32>,
+) {
     let topics = (Symbol::new(env, "role_granted"), role.clone(), account.clone());
-    env.events().publish(topics, admin.clone());
+    env.events().publish(topics, (admin.clone(), expiration_ledger));
 }
 
 /// Emitted when a role is revoked from an account
@@ -124,6 +396,21 @@ pub fn emit_role_revoked(env: &Env, role: &Symbol, account: &Address, admin: &Ad
     env.events().publish(topics, admin.clone());
 }
 
+/// Emitted when the admin role of a role is changed
+///
+/// Topics: ("role_admin_changed", role)
+/// Data: (previous_admin_role, new_admin_role)
+pub fn emit_role_admin_changed(
+    env: &Env,
+    role: &Symbol,
+    previous_admin_role: &Symbol,
+    new_admin_role: &Symbol,
+) {
+    let topics = (Symbol::new(env, "role_admin_changed"), role.clone());
+    env.events()
+        .publish(topics, (previous_admin_role.clone(), new_admin_role.clone()));
+}
+
 /// Emitted when ownership is transferred
 ///
 /// Topics: ("ownership_transferred", previous_owner, new_owner)
@@ -137,17 +424,174 @@ pub fn emit_ownership_transferred(env: &Env, previous_owner: &Address, new_owner
     env.events().publish(topics, ());
 }
 
+/// Emitted when the owner offers ownership to a new address
+///
+/// Topics: ("ownership_proposed", owner, new_owner)
+/// Data: expiration_ledger
+pub fn emit_ownership_proposed(
+    env: &Env,
+    owner: &Address,
+    new_owner: &Address,
+    expiration_ledger: Option<u32>,
+) {
+    let topics = (
+        Symbol::new(env, "ownership_proposed"),
+        owner.clone(),
+        new_owner.clone(),
+    );
+    env.events().publish(topics, expiration_ledger);
+}
+
+/// Emitted when the owner withdraws a pending ownership offer
+///
+/// Topics: ("ownership_cancelled", owner, new_owner)
+/// Data: ()
+pub fn emit_ownership_cancelled(env: &Env, owner: &Address, new_owner: &Address) {
+    let topics = (
+        Symbol::new(env, "ownership_cancelled"),
+        owner.clone(),
+        new_owner.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+/// Emitted when the owner renounces ownership
+///
+/// Topics: ("ownership_renounced", previous_owner)
+/// Data: ()
+pub fn emit_ownership_renounced(env: &Env, previous_owner: &Address) {
+    let topics = (Symbol::new(env, "ownership_renounced"), previous_owner.clone());
+    env.events().publish(topics, ());
+}
+
+// =============================================================================
+// CONFIGURATION EVENTS
+// =============================================================================
+
+/// Emitted when the contract configuration is updated
+///
+/// Topics: ("config_updated", owner)
+/// Data: config
+pub fn emit_config_updated(env: &Env, owner: &Address, config: &ContractConfig) {
+    let topics = (Symbol::new(env, "config_updated"), owner.clone());
+    env.events().publish(topics, config.clone());
+}
+
+/// Emitted when the transfer fee is configured or disabled
+///
+/// Topics: ("fee_config", owner)
+/// Data: Option<FeeConfig>
+pub fn emit_fee_config_updated(env: &Env, owner: &Address, config: &Option<FeeConfig>) {
+    let topics = (Symbol::new(env, "fee_config"), owner.clone());
+    env.events().publish(topics, config.clone());
+}
+
+/// Emitted when an account's transfer fee exemption changes
+///
+/// Topics: ("fee_exempt", account)
+/// Data: (exempt, owner)
+pub fn emit_fee_exemption(env: &Env, account: &Address, exempt: bool, owner: &Address) {
+    let topics = (Symbol::new(env, "fee_exempt"), account.clone());
+    env.events().publish(topics, (exempt, owner.clone()));
+}
+
+// =============================================================================
+// TRANSFER FEE EVENTS
+// =============================================================================
+
+/// Emitted alongside the fee `transfer` when a transfer is charged a fee
+///
+/// Topics: ("fee_charged", from, to)
+/// Data: (fee, collector)
+pub fn emit_fee_charged(env: &Env, from: &Address, to: &Address, fee: i128, collector: &Address) {
+    let topics = (Symbol::new(env, "fee_charged"), from.clone(), to.clone());
+    env.events().publish(topics, (fee, collector.clone()));
+}
+
+// =============================================================================
+// PROOF OF RESERVES EVENTS
+// =============================================================================
+
+/// Emitted when the proof-of-reserves gate is configured or disabled
+///
+/// Topics: ("reserve_config", owner)
+/// Data: Option<ReserveConfig>
+pub fn emit_reserve_config_updated(env: &Env, owner: &Address, config: &Option<ReserveConfig>) {
+    let topics = (Symbol::new(env, "reserve_config"), owner.clone());
+    env.events().publish(topics, config.clone());
+}
+
+/// Emitted when an attestor posts a reserve attestation
+///
+/// Topics: ("reserve_attested", attestor)
+/// Data: (reserves, as_of, report_hash)
+pub fn emit_reserve_attested(env: &Env, attestation: &ReserveAttestation) {
+    let topics = (Symbol::new(env, "reserve_attested"), attestation.attestor.clone());
+    let data = (attestation.reserves, attestation.as_of, attestation.report_hash.clone());
+    env.events().publish(topics, data);
+}
+
 // =============================================================================
 // UPGRADE EVENTS
 // =============================================================================
 
+/// Emitted when an upgrade is scheduled behind the timelock
+///
+/// Topics: ("upgrade_scheduled", operator)
+/// Data: (wasm_hash, eta_ledger)
+pub fn emit_upgrade_scheduled(
+    env: &Env,
+    operator: &Address,
+    wasm_hash: &BytesN<32>,
+    eta_ledger: u32,
+) {
+    let topics = (Symbol::new(env, "upgrade_scheduled"), operator.clone());
+    env.events().publish(topics, (wasm_hash.clone(), eta_ledger));
+}
+
+/// Emitted when a scheduled upgrade is cancelled
+///
+/// Topics: ("upgrade_cancelled", operator)
+/// Data: wasm_hash
+pub fn emit_upgrade_cancelled(env: &Env, operator: &Address, wasm_hash: &BytesN<32>) {
+    let topics = (Symbol::new(env, "upgrade_cancelled"), operator.clone());
+    env.events().publish(topics, wasm_hash.clone());
+}
+
+/// Emitted when the upgrade timelock delay changes
+///
+/// Topics: ("upgrade_delay", owner)
+/// Data: delay_ledgers
+pub fn emit_upgrade_delay_updated(env: &Env, owner: &Address, delay: u32) {
+    let topics = (Symbol::new(env, "upgrade_delay"), owner.clone());
+    env.events().publish(topics, delay);
+}
+
 /// Emitted when the contract is upgraded
 ///
-/// Topics: ("upgraded",)
-/// Data: new_wasm_hash (as bytes)
-pub fn emit_upgraded(env: &Env, operator: &Address) {
-    let topics = (Symbol::new(env, "upgraded"),);
-    env.events().publish(topics, operator.clone());
+/// Topics: ("upgraded", operator)
+/// Data: new_wasm_hash
+pub fn emit_upgraded(env: &Env, operator: &Address, wasm_hash: &BytesN<32>) {
+    let topics = (Symbol::new(env, "upgraded"), operator.clone());
+    env.events().publish(topics, wasm_hash.clone());
+}
+
+/// Emitted when stored state is migrated to a new contract version
+///
+/// Topics: ("migrated",)
+/// Data: (from_version, to_version)
+pub fn emit_migrated(env: &Env, from_version: u32, to_version: u32) {
+    let topics = (Symbol::new(env, "migrated"),);
+    env.events().publish(topics, (from_version, to_version));
+}
+
+/// Emitted when the owner backfills an index over existing records
+///
+/// Topics: ("backfilled", owner, index)
+/// Data: entries added
+pub fn emit_backfilled(env: &Env, owner: &Address, index: Symbol, added: u32) {
+    let topics = (Symbol::new(env, "backfilled"), owner.clone(), index);
+    env.events().publish(topics, added);
 }
 
 // =============================================================================
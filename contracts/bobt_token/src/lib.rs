@@ -5,9 +5,14 @@
 //! - Role-based access control
 //! - Pausable functionality
 //! - Blacklist/freeze capability
+//! - Permissioned (KYC tier) mode
 //! - Clawback support
 //! - Request tracking
-//! - Upgradeable contract
+//! - Timelocked upgrades with versioned migrations
+//! - Signed permit approvals and relayed (gasless) transfers
+//! - Optional transfer fee
+//! - Historical balance and supply checkpoints
+//! - Proof-of-reserves gate on minting
 //!
 //! # Architecture
 //!
@@ -16,17 +21,25 @@
 //! - `storage`: Storage keys, roles, and data types
 //! - `errors`: Custom error types
 //! - `events`: Event emission functions
+//! - `migration`: Versioned storage migrations
+//! - `signature`: Off-chain signature payloads and verification
 
 #![no_std]
 
 mod contract;
 mod errors;
 mod events;
+mod migration;
+mod signature;
 mod storage;
 
 pub use contract::BOBTToken;
 pub use errors::BOBTError;
-pub use storage::{BurnRequest, MintRequest};
+pub use storage::{
+    BurnRequest, ClawbackRecord, ContractConfig, FeeConfig, FreezeReason, FreezeRecord, Hold,
+    KycLevel, MintRequest, MintReservation, MintReversal, PendingOwner, PendingUpgrade, RequestKind,
+    RequestRef, ReserveAttestation, ReserveConfig, TierLimits,
+};
 
 #[cfg(test)]
 mod test;
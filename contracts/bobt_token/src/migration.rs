@@ -0,0 +1,169 @@
+//! Versioned storage migrations for BOBT Stablecoin
+//!
+//! Each step upgrades stored state from version N to N+1 and runs exactly
+//! once: `run` walks the steps from the stored version up to
+//! `CONTRACT_VERSION`, bumping `KEY_VERSION` after every step.
+//!
+//! Steps only rewrite keys they can find: instance storage and indexed
+//! persistent keys (owner, role member sets) are rewritten in place.
+//!
+//! Per-account and per-request keys cannot be enumerated, so indexes over
+//! them are rebuilt by backfills instead of steps. The owner passes
+//! candidate accounts or request IDs in batches of at most
+//! `MAX_BACKFILL_BATCH`; each candidate is confirmed on-chain and skipped if
+//! unconfirmed or already indexed, so a backfill can run any time after the
+//! version bump and be resumed or repeated.
+
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
+
+use crate::errors::BOBTError;
+use crate::events::emit_migrated;
+use crate::storage::{
+    self, DataKey, FreezeReason, FreezeRecord, RequestKind, CONTRACT_VERSION, ROLE_OWNER,
+};
+
+/// Run every pending migration step, returning the version migrated from
+///
+/// # Errors
+/// * `AlreadyMigrated` - If stored state is already at `CONTRACT_VERSION`
+/// * `MigrationFailed` - If the stored version is unknown to this build
+pub fn run(env: &Env) -> Result<u32, BOBTError> {
+    let from_version = storage::get_version(env);
+    if from_version == CONTRACT_VERSION {
+        return Err(BOBTError::AlreadyMigrated);
+    }
+    if from_version == 0 || from_version > CONTRACT_VERSION {
+        return Err(BOBTError::MigrationFailed);
+    }
+
+    let mut version = from_version;
+    while version < CONTRACT_VERSION {
+        match version {
+            1 => migrate_v1_to_v2(env),
+            2 => migrate_v2_to_v3(env),
+            3 => migrate_v3_to_v4(env),
+            4 => migrate_v4_to_v5(env),
+            5 => migrate_v5_to_v6(env),
+            _ => return Err(BOBTError::MigrationFailed),
+        }
+
+        storage::set_version(env, version + 1);
+        emit_migrated(env, version, version + 1);
+        version += 1;
+    }
+
+    Ok(from_version)
+}
+
+/// v2: legacy unauthenticated `mint` disabled (no storage changes)
+fn migrate_v1_to_v2(_env: &Env) {}
+
+/// v3: role member sets
+///
+/// Deployments from before role enumeration hold the owner role without a
+/// member set entry. Re-granting it seeds the set.
+fn migrate_v2_to_v3(env: &Env) {
+    if let Some(owner) = storage::get_owner(env) {
+        if storage::has_role(env, &ROLE_OWNER, &owner) {
+            storage::grant_role(env, &ROLE_OWNER, &owner, None);
+        }
+    }
+}
+
+/// v4: role member sets for every role (no storage changes)
+///
+/// Grants made before role enumeration are added by `backfill_role_members`.
+fn migrate_v3_to_v4(_env: &Env) {}
+
+/// v5: freeze records (no storage changes)
+///
+/// Legacy freeze flags are converted by `backfill_freezes`.
+fn migrate_v4_to_v5(_env: &Env) {}
+
+/// v6: used request ID set (no storage changes)
+///
+/// Requests recorded before the set existed are added by `backfill_requests`.
+fn migrate_v5_to_v6(_env: &Env) {}
+
+// =============================================================================
+// BACKFILLS - Resumable index rebuilds driven by the owner
+// =============================================================================
+
+/// Add existing grants of a role to its member set
+///
+/// Grants made before role enumeration (minters, pausers, blacklisters, ...)
+/// are missing from their member sets, so `get_role_members` and the
+/// `MAX_ROLE_MEMBERS` limit do not see them.
+///
+/// Returns the number of members added.
+pub fn backfill_role_members(env: &Env, role: &Symbol, accounts: &Vec<Address>) -> u32 {
+    let mut added = 0;
+    for account in accounts.iter() {
+        if storage::has_role(env, role, &account) && storage::add_role_member(env, role, &account)
+        {
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Convert legacy freeze flags into freeze records
+///
+/// Accounts frozen with the bare `Frozen` flag have no `FreezeRecord` and
+/// are missing from the frozen account list. Each candidate still carrying
+/// the flag gets a permanent record with reason `Legacy`, attributed to the
+/// owner, which also lists it.
+///
+/// Returns the number of records created.
+pub fn backfill_freezes(env: &Env, owner: &Address, accounts: &Vec<Address>) -> u32 {
+    let case_ref = String::from_str(env, "legacy");
+    let mut added = 0;
+    for account in accounts.iter() {
+        let flagged: bool = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Frozen(account.clone()))
+            .unwrap_or(false);
+        if flagged && storage::get_freeze_record(env, &account).is_none() {
+            let record = FreezeRecord {
+                reason: FreezeReason::Legacy,
+                case_ref: case_ref.clone(),
+                blacklister: owner.clone(),
+                ledger: env.ledger().sequence(),
+                expiration_ledger: None,
+            };
+            storage::freeze_account(env, &account, &record);
+            added += 1;
+        }
+    }
+    added
+}
+
+/// Add existing mint and burn requests to the history and used ID indexes
+///
+/// Requests recorded before the indexes existed can be fetched by ID but are
+/// not counted, listed or in the used ID set, whose record TTL can lapse and
+/// make the ID usable again. Each candidate with a stored record that is not
+/// in the used ID set yet is appended to the global and per-account
+/// histories, after any request indexed since the upgrade, and marked used.
+/// Indexed and unknown candidates are skipped, so calls can be repeated.
+///
+/// Returns the number of requests indexed.
+pub fn backfill_requests(env: &Env, request_ids: &Vec<String>) -> u32 {
+    let mut added = 0;
+    for request_id in request_ids.iter() {
+        if !storage::is_request_id_used(env, RequestKind::Mint, &request_id) {
+            if let Some(request) = storage::get_mint_request(env, &request_id) {
+                storage::index_mint_request(env, &request_id, &request);
+                added += 1;
+            }
+        }
+        if !storage::is_request_id_used(env, RequestKind::Burn, &request_id) {
+            if let Some(request) = storage::get_burn_request(env, &request_id) {
+                storage::index_burn_request(env, &request_id, &request);
+                added += 1;
+            }
+        }
+    }
+    added
+}
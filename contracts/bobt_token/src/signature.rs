@@ -0,0 +1,116 @@
+//! Off-chain signature verification for BOBT Stablecoin
+//!
+//! Holders without XLM can sign an ed25519 payload off-chain and let a
+//! relayer submit it. Every payload starts with a domain tag, the network ID
+//! (SHA-256 of the network passphrase) and this contract's address, so a
+//! signature cannot be replayed on another network or token contract.
+
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
+
+use crate::errors::BOBTError;
+
+/// Domain tag of `permit` payloads
+const PERMIT_DOMAIN: &[u8] = b"BOBT_PERMIT_V1";
+/// Domain tag of `transfer_with_authorization` payloads
+const TRANSFER_AUTH_DOMAIN: &[u8] = b"BOBT_TRANSFER_AUTH_V1";
+/// Domain tag of `cancel_authorization` payloads
+const CANCEL_AUTH_DOMAIN: &[u8] = b"BOBT_CANCEL_AUTH_V1";
+
+/// Length of an account address encoded as `ScVal` XDR
+const ACCOUNT_XDR_LEN: u32 = 44;
+/// Offset of the ed25519 key after the `ScVal`, `ScAddress` and key type tags
+const ACCOUNT_KEY_OFFSET: u32 = 12;
+
+/// Start a payload with the domain tag, network ID and contract address
+fn domain_payload(env: &Env, tag: &[u8]) -> Bytes {
+    let mut payload = Bytes::from_slice(env, tag);
+    payload.append(&env.ledger().network_id().into());
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload
+}
+
+/// Digest an owner signs to authorize a `permit` approval
+pub fn permit_digest(
+    env: &Env,
+    owner: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<32> {
+    let mut payload = domain_payload(env, PERMIT_DOMAIN);
+    payload.append(&owner.clone().to_xdr(env));
+    payload.append(&spender.clone().to_xdr(env));
+    payload.extend_from_array(&amount.to_be_bytes());
+    payload.extend_from_array(&expiration_ledger.to_be_bytes());
+    payload.extend_from_array(&nonce.to_be_bytes());
+    env.crypto().sha256(&payload).to_bytes()
+}
+
+/// Digest a holder signs to authorize a relayed `transfer_with_authorization`
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_authorization_digest(
+    env: &Env,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    fee: i128,
+    relayer: &Address,
+    valid_until_ledger: u32,
+    nonce: &BytesN<32>,
+) -> BytesN<32> {
+    let mut payload = domain_payload(env, TRANSFER_AUTH_DOMAIN);
+    payload.append(&from.clone().to_xdr(env));
+    payload.append(&to.clone().to_xdr(env));
+    payload.extend_from_array(&amount.to_be_bytes());
+    payload.extend_from_array(&fee.to_be_bytes());
+    payload.append(&relayer.clone().to_xdr(env));
+    payload.extend_from_array(&valid_until_ledger.to_be_bytes());
+    payload.append(&nonce.clone().into());
+    env.crypto().sha256(&payload).to_bytes()
+}
+
+/// Digest a holder signs to cancel an unused transfer authorization
+pub fn cancel_authorization_digest(env: &Env, from: &Address, nonce: &BytesN<32>) -> BytesN<32> {
+    let mut payload = domain_payload(env, CANCEL_AUTH_DOMAIN);
+    payload.append(&from.clone().to_xdr(env));
+    payload.append(&nonce.clone().into());
+    env.crypto().sha256(&payload).to_bytes()
+}
+
+/// Get the ed25519 public key behind a Stellar account (G...) address
+///
+/// # Errors
+/// * `InvalidSignature` - If the address is a contract, which cannot sign
+fn account_public_key(env: &Env, account: &Address) -> Result<BytesN<32>, BOBTError> {
+    let xdr = account.clone().to_xdr(env);
+    if xdr.len() != ACCOUNT_XDR_LEN {
+        return Err(BOBTError::InvalidSignature);
+    }
+    xdr.slice(ACCOUNT_KEY_OFFSET..ACCOUNT_XDR_LEN)
+        .try_into()
+        .map_err(|_| BOBTError::InvalidSignature)
+}
+
+/// Verify `signature` over `digest` by the key of `signer`
+///
+/// Checks the account's master key only, which the address encodes. Account
+/// signers, thresholds and a disabled master key (weight 0) are not
+/// consulted, so a multisig account or one whose master key was retired
+/// still authorizes with that key here. Such accounts should not rely on
+/// signed approvals.
+///
+/// `ed25519_verify` traps on a bad signature, so the invocation aborts with
+/// a host error rather than returning `InvalidSignature`, which is reserved
+/// for signers without an ed25519 key.
+pub fn verify(
+    env: &Env,
+    signer: &Address,
+    digest: &BytesN<32>,
+    signature: &BytesN<64>,
+) -> Result<(), BOBTError> {
+    let public_key = account_public_key(env, signer)?;
+    env.crypto()
+        .ed25519_verify(&public_key, &digest.clone().into(), signature);
+    Ok(())
+}
@@ -3,7 +3,9 @@
 //! Production-ready storage keys, roles, data types, and TTL configuration.
 //! Designed for scalability and gas efficiency.
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String, Symbol, Vec,
+};
 
 // =============================================================================
 // STORAGE KEYS - Using short symbols for gas efficiency
@@ -13,8 +15,21 @@ use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
 pub const KEY_INITIALIZED: Symbol = symbol_short!("INIT");
 pub const KEY_OWNER: Symbol = symbol_short!("OWNER");
 pub const KEY_PAUSED: Symbol = symbol_short!("PAUSED");
+pub const KEY_PAUSE_FLAGS: Symbol = symbol_short!("PAUSEFLG");
 pub const KEY_TOTAL_SUPPLY: Symbol = symbol_short!("TOTSUP");
 pub const KEY_VERSION: Symbol = symbol_short!("VERSION");
+pub const KEY_CONFIG: Symbol = symbol_short!("CONFIG");
+pub const KEY_PENDING_OWNER: Symbol = symbol_short!("PENDOWN");
+pub const KEY_PENDING_UPGRADE: Symbol = symbol_short!("PENDUPG");
+pub const KEY_UPGRADE_DELAY: Symbol = symbol_short!("UPGDELAY");
+pub const KEY_FROZEN_COUNT: Symbol = symbol_short!("FRZCNT");
+pub const KEY_FEE_CONFIG: Symbol = symbol_short!("FEECFG");
+pub const KEY_MINT_REQ_COUNT: Symbol = symbol_short!("MREQCNT");
+pub const KEY_BURN_REQ_COUNT: Symbol = symbol_short!("BREQCNT");
+pub const KEY_RESERVED_SUPPLY: Symbol = symbol_short!("RSVSUP");
+pub const KEY_RESERVE_CONFIG: Symbol = symbol_short!("RSVCFG");
+pub const KEY_RESERVE_ATTESTATION: Symbol = symbol_short!("RSVATT");
+pub const KEY_SUPPLY_CKPT_COUNT: Symbol = symbol_short!("SCKPTCNT");
 
 // =============================================================================
 // ROLE DEFINITIONS - Using 6-char symbols for consistency
@@ -24,12 +39,68 @@ pub const KEY_VERSION: Symbol = symbol_short!("VERSION");
 pub const ROLE_OWNER: Symbol = symbol_short!("R_OWN");
 /// Minter role - can mint/burn tokens for bank operations
 pub const ROLE_MINTER: Symbol = symbol_short!("R_MNT");
+/// Master minter role - manages per-minter mint allowances
+pub const ROLE_MASTER_MINTER: Symbol = symbol_short!("R_MMN");
 /// Pauser role - can pause/unpause in emergencies
 pub const ROLE_PAUSER: Symbol = symbol_short!("R_PSR");
 /// Blacklister role - can freeze accounts and clawback
 pub const ROLE_BLACKLISTER: Symbol = symbol_short!("R_BLK");
 /// Rescuer role - can rescue stuck tokens
 pub const ROLE_RESCUER: Symbol = symbol_short!("R_RSC");
+/// KYC officer role - approves holders and assigns KYC levels
+pub const ROLE_KYC_OFFICER: Symbol = symbol_short!("R_KYC");
+/// Compliance lead role - no direct powers; intended as an admin role for
+/// compliance roles (e.g. blacklisters) via `set_role_admin`
+pub const ROLE_COMPLIANCE: Symbol = symbol_short!("R_CMP");
+/// Hold operator role - places, releases and executes balance holds
+pub const ROLE_HOLD_OPERATOR: Symbol = symbol_short!("R_HLD");
+/// Attestor role - posts reserve attestations from the auditor's reports
+pub const ROLE_ATTESTOR: Symbol = symbol_short!("R_ATT");
+
+/// Maximum number of members per role (bounds enumeration cost)
+pub const MAX_ROLE_MEMBERS: u32 = 50;
+
+/// Maximum number of holds per account (bounds held-balance computation)
+pub const MAX_HOLDS_PER_ACCOUNT: u32 = 20;
+
+/// Maximum entries returned by one page of a list view
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum legs in one `batch_transfer`
+///
+/// Each leg reads the recipient's balance, freeze, fee exemption and KYC
+/// entries and writes its balance and balance checkpoints, so the
+/// per-transaction ledger entry limits are reached well before the CPU
+/// budget.
+pub const MAX_BATCH_SIZE: u32 = 20;
+
+/// Maximum length in bytes of a payment memo (e.g. a QR invoice ID)
+pub const MAX_MEMO_LEN: u32 = 64;
+
+/// Maximum length in bytes of a mint/burn request ID
+pub const MAX_REQUEST_ID_LEN: u32 = 64;
+
+/// Buckets of the used request ID set (keeps each bucket small)
+pub const REQUEST_ID_BUCKETS: u32 = 4096;
+
+/// Request ID hashes per used-ID shard; a full shard continues in a new one
+pub const REQUEST_IDS_PER_SHARD: u32 = 64;
+
+/// Buckets `extend_request_id_buckets` extends per call
+pub const MAX_BUCKETS_PER_EXTEND: u32 = 25;
+
+/// Maximum candidates per backfill call (bounds ledger entry footprint)
+pub const MAX_BACKFILL_BATCH: u32 = 10;
+
+/// Maximum request IDs per `backfill_requests` call; indexing a mint and a
+/// burn under one ID writes up to 12 entries
+pub const MAX_REQUEST_BACKFILL_BATCH: u32 = 4;
+
+/// Balance checkpoints kept per account (oldest dropped first)
+pub const MAX_BALANCE_CHECKPOINTS: u32 = 30;
+
+/// Total supply checkpoints kept (oldest dropped first)
+pub const MAX_SUPPLY_CHECKPOINTS: u32 = 200;
 
 // =============================================================================
 // STORAGE KEY TYPES - Structured for efficient lookups
@@ -44,14 +115,60 @@ pub enum DataKey {
     Allowance(Address, Address),
     /// Role assignment: DataKey::Role(role, account) -> bool
     Role(Symbol, Address),
-    /// Account frozen status: DataKey::Frozen(address) -> bool
+    /// Role grant expiry: DataKey::RoleExpiry(role, account) -> u32 (last valid ledger)
+    RoleExpiry(Symbol, Address),
+    /// Role members: DataKey::RoleMembers(role) -> Vec<Address>
+    RoleMembers(Symbol),
+    /// Admin role that manages a role: DataKey::RoleAdmin(role) -> Symbol
+    RoleAdmin(Symbol),
+    /// Remaining mint allowance of a minter: DataKey::MinterAllowance(minter) -> i128
+    MinterAllowance(Address),
+    /// Legacy (pre-FreezeRecord) frozen flag: DataKey::Frozen(address) -> bool
     Frozen(Address),
+    /// Account freeze details: DataKey::FreezeRec(address) -> FreezeRecord
+    FreezeRec(Address),
+    /// Frozen account list slot: DataKey::FrozenIdx(index) -> Address
+    FrozenIdx(u32),
+    /// Slot of a frozen account in the list: DataKey::FrozenPos(address) -> u32
+    FrozenPos(Address),
+    /// Account KYC level: DataKey::Kyc(address) -> KycLevel
+    Kyc(Address),
+    /// Limits for a KYC level: DataKey::KycTier(level) -> TierLimits
+    KycTier(KycLevel),
     /// Mint request tracking: DataKey::MintReq(id) -> MintRequest
     MintReq(String),
     /// Burn request tracking: DataKey::BurnReq(id) -> BurnRequest
     BurnReq(String),
-    /// Nonce for replay protection: DataKey::Nonce(address) -> u64
+    /// Mint request history slot: DataKey::MintReqIdx(sequence) -> request_id
+    MintReqIdx(u32),
+    /// Burn request history slot: DataKey::BurnReqIdx(sequence) -> request_id
+    BurnReqIdx(u32),
+    /// Requests involving an account: DataKey::AcctReqCount(address) -> u32
+    AcctReqCount(Address),
+    /// Account request history slot: DataKey::AcctReqIdx(address, index) -> RequestRef
+    AcctReqIdx(Address, u32),
+    /// Used request ID hashes: DataKey::ReqIdShard(bucket, shard) -> Map<BytesN<32>, bool>
+    ReqIdShard(u32, u32),
+    /// Pending two-phase mint: DataKey::MintRsv(request_id) -> MintReservation
+    MintRsv(String),
+    /// Reversal of a mint: DataKey::MintRev(request_id) -> MintReversal
+    MintRev(String),
+    /// Balance history: DataKey::BalCkpt(address) -> Vec<Checkpoint>
+    BalCkpt(Address),
+    /// Total supply history slot: DataKey::SupplyCkpt(slot) -> Checkpoint
+    SupplyCkpt(u32),
+    /// Clawback tracking: DataKey::Clawback(legal_ref) -> ClawbackRecord
+    Clawback(String),
+    /// Balance hold: DataKey::Hold(hold_id) -> Hold
+    Hold(String),
+    /// Holds placed on an account: DataKey::AccountHolds(address) -> Vec<String>
+    AccountHolds(Address),
+    /// Next permit nonce of an account: DataKey::Nonce(address) -> u64
     Nonce(Address),
+    /// Used or canceled transfer authorization: DataKey::AuthNonce(from, nonce) -> bool
+    AuthNonce(Address, BytesN<32>),
+    /// Transfer fee exemption: DataKey::FeeExempt(address) -> bool
+    FeeExempt(Address),
 }
 
 // =============================================================================
@@ -102,6 +219,134 @@ pub struct BurnRequest {
     pub external_ref: String,
 }
 
+/// Mint reserved against a bank deposit that has not settled yet
+///
+/// Reserved amounts count against `MAX_SUPPLY` and the minter's allowance
+/// but are not part of any balance until the mint is confirmed.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct MintReservation {
+    /// Recipient once confirmed
+    pub to: Address,
+    /// Amount reserved (in stroops)
+    pub amount: i128,
+    /// Minter whose allowance was consumed
+    pub minter: Address,
+    /// Ledger sequence when reserved
+    pub ledger: u32,
+    /// Last ledger the mint can be confirmed
+    pub confirm_by_ledger: u32,
+}
+
+/// Value of a balance or the total supply at the end of a ledger
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Checkpoint {
+    /// Ledger sequence of the last change
+    pub ledger: u32,
+    /// Value after the change (in stroops)
+    pub value: i128,
+}
+
+/// Kind of a tracked request
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum RequestKind {
+    Mint = 0,
+    Burn = 1,
+}
+
+/// Entry of an account's request history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestRef {
+    /// Mint or burn
+    pub kind: RequestKind,
+    /// External reference ID of the request
+    pub request_id: String,
+    /// Position in the global mint or burn history
+    pub sequence: u32,
+}
+
+/// Clawback (seizure) with complete audit trail
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ClawbackRecord {
+    /// Account funds were taken from
+    pub account: Address,
+    /// Amount clawed back (in stroops)
+    pub amount: i128,
+    /// Custody account that received the funds (None = burned)
+    pub destination: Option<Address>,
+    /// Ledger sequence when executed
+    pub ledger: u32,
+    /// Timestamp when executed
+    pub timestamp: u64,
+    /// Blacklister who executed
+    pub blacklister: Address,
+    /// Legal reference (court order or case number)
+    pub legal_ref: String,
+}
+
+/// Reversal (chargeback) of a mint whose bank deposit proved fraudulent
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct MintReversal {
+    /// Recipient of the original mint
+    pub to: Address,
+    /// Amount of the original mint (in stroops)
+    pub minted: i128,
+    /// Amount burned from the recipient (in stroops)
+    pub burned: i128,
+    /// Ledger sequence when reversed
+    pub ledger: u32,
+    /// Timestamp when reversed
+    pub timestamp: u64,
+    /// Blacklister who executed
+    pub blacklister: Address,
+    /// Reason for the reversal
+    pub reason: String,
+}
+
+/// Portion of an account's balance locked by a hold operator
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Hold {
+    /// Account whose funds are held
+    pub account: Address,
+    /// Amount held (in stroops)
+    pub amount: i128,
+    /// Hold operator who placed the hold
+    pub operator: Address,
+    /// Ledger sequence when placed
+    pub ledger: u32,
+    /// Last ledger the hold applies to (None = until released or executed)
+    pub expiration_ledger: Option<u32>,
+}
+
+/// Pending two-step ownership offer
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PendingOwner {
+    /// Address that must accept the offer
+    pub new_owner: Address,
+    /// Last ledger at which the offer can be accepted (None = no expiry)
+    pub expiration_ledger: Option<u32>,
+}
+
+/// Upgrade waiting out its timelock
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PendingUpgrade {
+    /// Hash of the WASM binary that will be installed
+    pub wasm_hash: BytesN<32>,
+    /// Ledger the upgrade was scheduled at
+    pub scheduled_ledger: u32,
+    /// First ledger at which the upgrade can be executed
+    pub eta_ledger: u32,
+}
+
 /// Contract configuration
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -116,6 +361,128 @@ pub struct ContractConfig {
     pub require_whitelist: bool,
 }
 
+/// Transfer fee charged to senders and paid to a collector
+///
+/// The fee is `rate_bps` of the amount, raised to `min_fee` and capped at
+/// `max_fee`. It is taken out of the amount sent, so the recipient receives
+/// the amount minus the fee.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Fee rate in basis points of the transfer amount
+    pub rate_bps: u32,
+    /// Flat minimum fee (in stroops)
+    pub min_fee: i128,
+    /// Maximum fee per transfer (in stroops)
+    pub max_fee: i128,
+    /// Address receiving collected fees
+    pub collector: Address,
+}
+
+/// Proof-of-reserves gate on minting
+///
+/// While set, mints require an attestation no older than `max_age` seconds
+/// and may not push total supply above its attested reserves.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveConfig {
+    /// Maximum age of the latest attestation (seconds since `as_of`)
+    pub max_age: u64,
+}
+
+/// Reserve attestation posted by an attestor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveAttestation {
+    /// Attested fiat reserves backing the token (in stroops)
+    pub reserves: i128,
+    /// Timestamp the reserves were measured at
+    pub as_of: u64,
+    /// Hash of the auditor's report
+    pub report_hash: BytesN<32>,
+    /// Attestor who posted it
+    pub attestor: Address,
+    /// Ledger sequence when posted
+    pub ledger: u32,
+}
+
+/// KYC verification level of a holder
+///
+/// Only consulted while `ContractConfig::require_whitelist` is enabled.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum KycLevel {
+    /// Not approved - cannot hold or receive tokens in permissioned mode
+    None = 0,
+    /// Approved wallet without identity verification
+    Unverified = 1,
+    /// Basic identity verification completed
+    Basic = 2,
+    /// Full KYC completed
+    Full = 3,
+}
+
+/// Reason code recorded when an account is frozen
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum FreezeReason {
+    /// Reason not covered by another code
+    Other = 0,
+    /// Sanctions list match
+    Sanctions = 1,
+    /// Court or regulator order
+    CourtOrder = 2,
+    /// Suspected fraud or stolen funds
+    Fraud = 3,
+    /// Temporary hold during an investigation or dispute
+    Investigation = 4,
+    /// Freeze flag set before freeze records, converted by migration
+    Legacy = 5,
+}
+
+/// Why, when and by whom an account was frozen
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FreezeRecord {
+    /// Reason code
+    pub reason: FreezeReason,
+    /// Compliance case or legal reference
+    pub case_ref: String,
+    /// Blacklister who froze the account
+    pub blacklister: Address,
+    /// Ledger sequence when frozen
+    pub ledger: u32,
+    /// Last ledger the freeze applies to (None = until unfrozen)
+    pub expiration_ledger: Option<u32>,
+}
+
+/// Holding and transfer limits applied to a KYC level
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct TierLimits {
+    /// Maximum balance an account at this level may hold
+    pub max_balance: i128,
+    /// Maximum amount per outgoing transfer
+    pub max_transfer: i128,
+}
+
+// =============================================================================
+// PAUSE FLAGS - One bit per operation class
+// =============================================================================
+
+/// Blocks `transfer`, `transfer_from` and hold payouts
+pub const PAUSE_TRANSFER: u32 = 1 << 0;
+/// Blocks minting
+pub const PAUSE_MINT: u32 = 1 << 1;
+/// Blocks `burn`, `burn_from` and `admin_burn`
+pub const PAUSE_BURN: u32 = 1 << 2;
+/// Blocks `approve`
+pub const PAUSE_APPROVE: u32 = 1 << 3;
+/// Every operation class
+pub const PAUSE_ALL: u32 = PAUSE_TRANSFER | PAUSE_MINT | PAUSE_BURN | PAUSE_APPROVE;
+
 // =============================================================================
 // TOKEN CONSTANTS - SEP-41 compliant
 // =============================================================================
@@ -127,7 +494,18 @@ pub const TOKEN_NAME: &str = "BOBT Stablecoin";
 /// Token symbol
 pub const TOKEN_SYMBOL: &str = "BOBT";
 /// Contract version for upgrades
-pub const CONTRACT_VERSION: u32 = 1;
+pub const CONTRACT_VERSION: u32 = 6;
+
+// =============================================================================
+// UPGRADE TIMELOCK - Delays in ledgers (~5s each)
+// =============================================================================
+
+/// Shortest delay the owner can configure: ~1 day
+pub const MIN_UPGRADE_DELAY: u32 = 17280;
+/// Delay used until the owner configures one: ~2 days
+pub const DEFAULT_UPGRADE_DELAY: u32 = 17280 * 2;
+/// Longest delay the owner can configure: ~30 days
+pub const MAX_UPGRADE_DELAY: u32 = 17280 * 30;
 
 // =============================================================================
 // AMOUNT CONSTANTS - Production limits
@@ -137,14 +515,27 @@ pub const CONTRACT_VERSION: u32 = 1;
 pub const ONE_TOKEN: i128 = 10_000_000;
 /// Maximum supply: 1 trillion BOBT
 pub const MAX_SUPPLY: i128 = 1_000_000_000_000 * ONE_TOKEN;
-/// Default max mint per transaction: 100 million BOBT (reserved for future use)
-#[allow(dead_code)]
+/// Default max mint per transaction: 100 million BOBT
 pub const DEFAULT_MAX_MINT: i128 = 100_000_000 * ONE_TOKEN;
-/// Default max burn per transaction: 100 million BOBT (reserved for future use)
-#[allow(dead_code)]
+/// Default max burn per transaction: 100 million BOBT
 pub const DEFAULT_MAX_BURN: i128 = 100_000_000 * ONE_TOKEN;
 /// Minimum transfer: 0.0000001 BOBT (1 stroop)
 pub const MIN_TRANSFER: i128 = 1;
+/// Highest transfer fee rate the owner can configure: 10%
+pub const MAX_FEE_BPS: u32 = 1_000;
+/// Basis points in 100%
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+// =============================================================================
+// KYC TIER DEFAULTS - Can be changed by the owner
+// =============================================================================
+
+/// Unverified wallets: 3,500 BOBT balance, 1,000 BOBT per transfer
+pub const DEFAULT_UNVERIFIED_MAX_BALANCE: i128 = 3_500 * ONE_TOKEN;
+pub const DEFAULT_UNVERIFIED_MAX_TRANSFER: i128 = 1_000 * ONE_TOKEN;
+/// Basic KYC: 70,000 BOBT balance, 35,000 BOBT per transfer
+pub const DEFAULT_BASIC_MAX_BALANCE: i128 = 70_000 * ONE_TOKEN;
+pub const DEFAULT_BASIC_MAX_TRANSFER: i128 = 35_000 * ONE_TOKEN;
 
 // =============================================================================
 // STORAGE TTL CONFIGURATION - Optimized for production
@@ -182,6 +573,25 @@ pub fn extend_persistent_ttl(env: &Env, key: &DataKey) {
     }
 }
 
+/// Extend persistent storage TTL for a DataKey to the network maximum
+pub fn extend_persistent_ttl_max(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        let max_ttl = env.storage().max_ttl();
+        env.storage().persistent().extend_ttl(key, max_ttl, max_ttl);
+    }
+}
+
+/// Extend persistent storage TTL for a DataKey to outlive `last_ledger`
+///
+/// Extends by at least `PERSISTENT_TTL_EXTEND`, capped at the network maximum.
+pub fn extend_persistent_ttl_until(env: &Env, key: &DataKey, last_ledger: u32) {
+    let until_last = last_ledger.saturating_sub(env.ledger().sequence());
+    let extend_to = until_last
+        .max(PERSISTENT_TTL_EXTEND)
+        .min(env.storage().max_ttl());
+    env.storage().persistent().extend_ttl(key, extend_to, extend_to);
+}
+
 /// Extend temporary storage TTL for a DataKey
 pub fn extend_temporary_ttl(env: &Env, key: &DataKey) {
     if env.storage().temporary().has(key) {
@@ -206,9 +616,18 @@ pub fn get_balance(env: &Env, account: &Address) -> i128 {
     }
 }
 
-/// Set balance with TTL extension
+/// Set balance with TTL extension, recording a balance checkpoint
 pub fn set_balance(env: &Env, account: &Address, amount: i128) {
     let key = DataKey::Balance(account.clone());
+    let checkpoint_key = DataKey::BalCkpt(account.clone());
+    write_checkpoint(
+        env,
+        &checkpoint_key,
+        || env.storage().persistent().get(&key).unwrap_or(0),
+        amount,
+        MAX_BALANCE_CHECKPOINTS,
+    );
+
     env.storage().persistent().set(&key, &amount);
     extend_persistent_ttl(env, &key);
 }
@@ -221,11 +640,161 @@ pub fn get_total_supply(env: &Env) -> i128 {
         .unwrap_or(0)
 }
 
-/// Set total supply
+/// Set total supply, recording a supply checkpoint
 pub fn set_total_supply(env: &Env, amount: i128) {
+    write_supply_checkpoint(env, amount);
     env.storage().instance().set(&KEY_TOTAL_SUPPLY, &amount);
 }
 
+// =============================================================================
+// CHECKPOINTS - Historical balances and supply
+// =============================================================================
+//
+// Each list holds the value at the end of every ledger it changed in, oldest
+// first, and keeps only the most recent entries. A list started from zero
+// opens with a (0, 0) entry so it also answers for every earlier ledger;
+// a list started from an existing value (state from before checkpoints were
+// recorded) or trimmed by retention cannot answer before its first entry.
+//
+// Balance lists are short and stored as one Vec per account. The supply
+// changes with every mint and burn, so its list is a ring buffer of
+// single-checkpoint slots, `DataKey::SupplyCkpt(position % MAX_SUPPLY_CHECKPOINTS)`,
+// with the number of checkpoints ever written kept in instance storage. A
+// supply change writes one slot instead of the whole history. Slots are
+// extended to the maximum TTL when written.
+
+/// Record `value` as of the current ledger in a checkpoint list
+fn write_checkpoint(
+    env: &Env,
+    key: &DataKey,
+    previous: impl FnOnce() -> i128,
+    value: i128,
+    retention: u32,
+) {
+    let ledger = env.ledger().sequence();
+    let mut checkpoints: Vec<Checkpoint> =
+        env.storage().persistent().get(key).unwrap_or_else(|| {
+            let mut checkpoints = Vec::new(env);
+            if previous() == 0 {
+                checkpoints.push_back(Checkpoint { ledger: 0, value: 0 });
+            }
+            checkpoints
+        });
+
+    let checkpoint = Checkpoint { ledger, value };
+    match checkpoints.last() {
+        Some(last) if last.ledger == ledger => checkpoints.set(checkpoints.len() - 1, checkpoint),
+        _ => checkpoints.push_back(checkpoint),
+    }
+    while checkpoints.len() > retention {
+        checkpoints.pop_front();
+    }
+
+    env.storage().persistent().set(key, &checkpoints);
+    extend_persistent_ttl(env, key);
+}
+
+/// Number of supply checkpoints ever written
+fn get_supply_checkpoint_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&KEY_SUPPLY_CKPT_COUNT)
+        .unwrap_or(0)
+}
+
+/// Get the supply checkpoint at a retained position of the history
+fn get_supply_checkpoint(env: &Env, position: u32) -> Checkpoint {
+    env.storage()
+        .persistent()
+        .get(&DataKey::SupplyCkpt(position % MAX_SUPPLY_CHECKPOINTS))
+        .unwrap()
+}
+
+/// Store the supply checkpoint at a position of the history
+fn set_supply_checkpoint(env: &Env, position: u32, checkpoint: &Checkpoint) {
+    let key = DataKey::SupplyCkpt(position % MAX_SUPPLY_CHECKPOINTS);
+    env.storage().persistent().set(&key, checkpoint);
+    extend_persistent_ttl_max(env, &key);
+}
+
+/// Record `value` as the total supply at the current ledger
+fn write_supply_checkpoint(env: &Env, value: i128) {
+    let ledger = env.ledger().sequence();
+    let mut count = get_supply_checkpoint_count(env);
+    if count == 0 && get_total_supply(env) == 0 {
+        set_supply_checkpoint(env, 0, &Checkpoint { ledger: 0, value: 0 });
+        count = 1;
+    }
+
+    let checkpoint = Checkpoint { ledger, value };
+    if count > 0 && get_supply_checkpoint(env, count - 1).ledger == ledger {
+        set_supply_checkpoint(env, count - 1, &checkpoint);
+    } else {
+        // Overwrites the oldest slot once the ring buffer is full
+        set_supply_checkpoint(env, count, &checkpoint);
+        count += 1;
+    }
+    env.storage().instance().set(&KEY_SUPPLY_CKPT_COUNT, &count);
+}
+
+/// Binary search over `len` checkpoints for the value at the end of `ledger`
+fn checkpoint_at(len: u32, checkpoint: impl Fn(u32) -> Checkpoint, ledger: u32) -> Option<i128> {
+    // Find the first checkpoint after `ledger`; the one before it applies
+    let mut low = 0;
+    let mut high = len;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if checkpoint(mid).ledger <= ledger {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == 0 {
+        return None;
+    }
+    Some(checkpoint(low - 1).value)
+}
+
+/// Get an account's balance at the end of a past ledger
+///
+/// Returns None for future ledgers and ledgers outside the retained history.
+pub fn get_balance_at(env: &Env, account: &Address, ledger: u32) -> Option<i128> {
+    if ledger > env.ledger().sequence() {
+        return None;
+    }
+    let key = DataKey::BalCkpt(account.clone());
+    match env.storage().persistent().get::<_, Vec<Checkpoint>>(&key) {
+        Some(checkpoints) => {
+            checkpoint_at(checkpoints.len(), |i| checkpoints.get_unchecked(i), ledger)
+        }
+        // Never written since checkpoints were introduced
+        None => match get_balance(env, account) {
+            0 => Some(0),
+            _ => None,
+        },
+    }
+}
+
+/// Get the total supply at the end of a past ledger
+///
+/// Returns None for future ledgers and ledgers outside the retained history.
+pub fn get_total_supply_at(env: &Env, ledger: u32) -> Option<i128> {
+    if ledger > env.ledger().sequence() {
+        return None;
+    }
+    match get_supply_checkpoint_count(env) {
+        0 => match get_total_supply(env) {
+            0 => Some(0),
+            _ => None,
+        },
+        count => {
+            let first = count.saturating_sub(MAX_SUPPLY_CHECKPOINTS);
+            checkpoint_at(count - first, |i| get_supply_checkpoint(env, first + i), ledger)
+        }
+    }
+}
+
 // =============================================================================
 // ALLOWANCE OPERATIONS - With expiration support
 // =============================================================================
@@ -307,63 +876,492 @@ pub fn spend_allowance(
 // ROLE OPERATIONS
 // =============================================================================
 
-/// Check if account has role
+/// Check if account has an active (granted and unexpired) role
 pub fn has_role(env: &Env, role: &Symbol, account: &Address) -> bool {
     let key = DataKey::Role(role.clone(), account.clone());
-    env.storage().persistent().get(&key).unwrap_or(false)
+    if !env.storage().persistent().get(&key).unwrap_or(false) {
+        return false;
+    }
+    match get_role_expiration(env, role, account) {
+        Some(expiration) => expiration >= env.ledger().sequence(),
+        None => true,
+    }
 }
 
-/// Grant role to account
-pub fn grant_role(env: &Env, role: &Symbol, account: &Address) {
+/// Get the last ledger at which a role grant is valid (None = permanent)
+pub fn get_role_expiration(env: &Env, role: &Symbol, account: &Address) -> Option<u32> {
+    let key = DataKey::RoleExpiry(role.clone(), account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Grant role to account and add it to the role's member set
+///
+/// `expiration_ledger` is the last ledger at which the grant is valid;
+/// `None` grants the role permanently.
+pub fn grant_role(env: &Env, role: &Symbol, account: &Address, expiration_ledger: Option<u32>) {
     let key = DataKey::Role(role.clone(), account.clone());
     env.storage().persistent().set(&key, &true);
     extend_persistent_ttl(env, &key);
+
+    let expiry_key = DataKey::RoleExpiry(role.clone(), account.clone());
+    if let Some(expiration) = expiration_ledger {
+        env.storage().persistent().set(&expiry_key, &expiration);
+        extend_persistent_ttl(env, &expiry_key);
+    } else {
+        env.storage().persistent().remove(&expiry_key);
+    }
+
+    let mut members = get_role_members(env, role);
+    if !members.contains(account) {
+        members.push_back(account.clone());
+        set_role_members(env, role, &members);
+    }
 }
 
-/// Revoke role from account
+/// Revoke role from account and remove it from the role's member set
 pub fn revoke_role(env: &Env, role: &Symbol, account: &Address) {
     let key = DataKey::Role(role.clone(), account.clone());
     env.storage().persistent().remove(&key);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::RoleExpiry(role.clone(), account.clone()));
+
+    let mut members = get_role_members(env, role);
+    if let Some(index) = members.first_index_of(account) {
+        members.remove(index);
+        set_role_members(env, role, &members);
+    }
+}
+
+/// Add an existing grant to the role's member set, returning whether it was added
+///
+/// Unlike `grant_role` this leaves the grant and its expiration untouched.
+pub fn add_role_member(env: &Env, role: &Symbol, account: &Address) -> bool {
+    let mut members = get_role_members(env, role);
+    if members.contains(account) {
+        return false;
+    }
+    members.push_back(account.clone());
+    set_role_members(env, role, &members);
+    true
+}
+
+/// Get the members of a role whose grants are still active
+pub fn get_active_role_members(env: &Env, role: &Symbol) -> Vec<Address> {
+    let mut active = Vec::new(env);
+    for member in get_role_members(env, role).iter() {
+        if has_role(env, role, &member) {
+            active.push_back(member);
+        }
+    }
+    active
+}
+
+/// Revoke expired grants of a role so they stop counting towards its member limit
+pub fn prune_expired_role_members(env: &Env, role: &Symbol) {
+    for member in get_role_members(env, role).iter() {
+        if !has_role(env, role, &member) {
+            revoke_role(env, role, &member);
+        }
+    }
+}
+
+/// Get all members of a role, including expired grants
+pub fn get_role_members(env: &Env, role: &Symbol) -> Vec<Address> {
+    let key = DataKey::RoleMembers(role.clone());
+    if let Some(members) = env.storage().persistent().get::<_, Vec<Address>>(&key) {
+        extend_persistent_ttl(env, &key);
+        members
+    } else {
+        Vec::new(env)
+    }
+}
+
+/// Store the member set of a role
+fn set_role_members(env: &Env, role: &Symbol, members: &Vec<Address>) {
+    let key = DataKey::RoleMembers(role.clone());
+    if members.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, members);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Get the admin role of a role (defaults to the owner role)
+pub fn get_role_admin(env: &Env, role: &Symbol) -> Symbol {
+    let key = DataKey::RoleAdmin(role.clone());
+    env.storage().persistent().get(&key).unwrap_or(ROLE_OWNER)
+}
+
+/// Set the admin role of a role
+pub fn set_role_admin(env: &Env, role: &Symbol, admin_role: &Symbol) {
+    let key = DataKey::RoleAdmin(role.clone());
+    if *admin_role == ROLE_OWNER {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, admin_role);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+// =============================================================================
+// MINTER ALLOWANCE OPERATIONS
+// =============================================================================
+
+/// Get the remaining mint allowance of a minter (0 if not configured)
+pub fn get_minter_allowance(env: &Env, minter: &Address) -> i128 {
+    let key = DataKey::MinterAllowance(minter.clone());
+    if let Some(allowance) = env.storage().persistent().get::<_, i128>(&key) {
+        extend_persistent_ttl(env, &key);
+        allowance
+    } else {
+        0
+    }
+}
+
+/// Set the remaining mint allowance of a minter
+pub fn set_minter_allowance(env: &Env, minter: &Address, allowance: i128) {
+    let key = DataKey::MinterAllowance(minter.clone());
+    env.storage().persistent().set(&key, &allowance);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Remove a minter's allowance entirely
+pub fn remove_minter_allowance(env: &Env, minter: &Address) {
+    let key = DataKey::MinterAllowance(minter.clone());
+    env.storage().persistent().remove(&key);
 }
 
 // =============================================================================
 // FREEZE OPERATIONS
 // =============================================================================
+//
+// Freeze records and frozen list entries are extended to the maximum TTL
+// when written and whenever `get_frozen_at` reads their slot, so paging
+// through the whole list with `frozen_accounts` at least once per maximum
+// TTL keeps every freeze alive (a keeper job).
 
-/// Check if account is frozen
+/// Check if account is frozen (records lapse after their expiration ledger)
 pub fn is_frozen(env: &Env, account: &Address) -> bool {
-    let key = DataKey::Frozen(account.clone());
-    env.storage().persistent().get(&key).unwrap_or(false)
+    match get_freeze_record(env, account) {
+        Some(record) => is_freeze_active(env, &record),
+        None => {
+            let key = DataKey::Frozen(account.clone());
+            env.storage().persistent().get(&key).unwrap_or(false)
+        }
+    }
+}
+
+/// Check if a freeze record still applies at the current ledger
+pub fn is_freeze_active(env: &Env, record: &FreezeRecord) -> bool {
+    match record.expiration_ledger {
+        Some(expiration) => expiration >= env.ledger().sequence(),
+        None => true,
+    }
+}
+
+/// Get the freeze record of an account
+pub fn get_freeze_record(env: &Env, account: &Address) -> Option<FreezeRecord> {
+    let key = DataKey::FreezeRec(account.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Freeze account, replacing any legacy flag and adding it to the frozen list
+pub fn freeze_account(env: &Env, account: &Address, record: &FreezeRecord) {
+    let key = DataKey::FreezeRec(account.clone());
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl_max(env, &key);
+
+    env.storage().persistent().remove(&DataKey::Frozen(account.clone()));
+
+    let pos_key = DataKey::FrozenPos(account.clone());
+    if !env.storage().persistent().has(&pos_key) {
+        let count = get_frozen_count(env);
+        let idx_key = DataKey::FrozenIdx(count);
+        env.storage().persistent().set(&idx_key, account);
+        extend_persistent_ttl_max(env, &idx_key);
+        env.storage().persistent().set(&pos_key, &count);
+        extend_persistent_ttl_max(env, &pos_key);
+        env.storage().instance().set(&KEY_FROZEN_COUNT, &(count + 1));
+    }
+}
+
+/// Unfreeze account and remove it from the frozen list
+pub fn unfreeze_account(env: &Env, account: &Address) {
+    env.storage().persistent().remove(&DataKey::FreezeRec(account.clone()));
+    env.storage().persistent().remove(&DataKey::Frozen(account.clone()));
+
+    // Swap-remove: move the last entry into the freed slot
+    let pos_key = DataKey::FrozenPos(account.clone());
+    if let Some(pos) = env.storage().persistent().get::<_, u32>(&pos_key) {
+        let last = get_frozen_count(env) - 1;
+        if pos != last {
+            let moved: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::FrozenIdx(last))
+                .unwrap();
+            let idx_key = DataKey::FrozenIdx(pos);
+            env.storage().persistent().set(&idx_key, &moved);
+            extend_persistent_ttl_max(env, &idx_key);
+            let moved_pos_key = DataKey::FrozenPos(moved);
+            env.storage().persistent().set(&moved_pos_key, &pos);
+            extend_persistent_ttl_max(env, &moved_pos_key);
+        }
+        env.storage().persistent().remove(&DataKey::FrozenIdx(last));
+        env.storage().persistent().remove(&pos_key);
+        env.storage().instance().set(&KEY_FROZEN_COUNT, &last);
+    }
+}
+
+/// Number of accounts in the frozen list (including lapsed freezes)
+pub fn get_frozen_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&KEY_FROZEN_COUNT)
+        .unwrap_or(0)
+}
+
+/// Get the account at a slot of the frozen list, extending its freeze entries
+pub fn get_frozen_at(env: &Env, index: u32) -> Option<Address> {
+    let key = DataKey::FrozenIdx(index);
+    let account: Address = env.storage().persistent().get(&key)?;
+    extend_persistent_ttl_max(env, &key);
+    extend_persistent_ttl_max(env, &DataKey::FrozenPos(account.clone()));
+    extend_persistent_ttl_max(env, &DataKey::FreezeRec(account.clone()));
+    Some(account)
+}
+
+// =============================================================================
+// SIGNATURE NONCES
+// =============================================================================
+
+/// Get the next permit nonce of an account
+pub fn get_nonce(env: &Env, account: &Address) -> u64 {
+    let key = DataKey::Nonce(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Set the next permit nonce of an account
+pub fn set_nonce(env: &Env, account: &Address, nonce: u64) {
+    let key = DataKey::Nonce(account.clone());
+    env.storage().persistent().set(&key, &nonce);
+    extend_persistent_ttl_max(env, &key);
+}
+
+/// Check if a transfer authorization nonce was used or canceled
+pub fn is_authorization_used(env: &Env, from: &Address, nonce: &BytesN<32>) -> bool {
+    let key = DataKey::AuthNonce(from.clone(), nonce.clone());
+    env.storage().persistent().has(&key)
 }
 
-/// Freeze account
-pub fn freeze_account(env: &Env, account: &Address) {
-    let key = DataKey::Frozen(account.clone());
+/// Mark a transfer authorization nonce as used or canceled
+///
+/// The mark lives at least until `valid_until_ledger`, the last ledger the
+/// authorization could be submitted (capped at the maximum TTL).
+pub fn mark_authorization_used(
+    env: &Env,
+    from: &Address,
+    nonce: &BytesN<32>,
+    valid_until_ledger: u32,
+) {
+    let key = DataKey::AuthNonce(from.clone(), nonce.clone());
     env.storage().persistent().set(&key, &true);
+    extend_persistent_ttl_until(env, &key, valid_until_ledger);
+}
+
+// =============================================================================
+// BALANCE HOLDS
+// =============================================================================
+
+/// Get a hold by ID
+pub fn get_hold(env: &Env, hold_id: &String) -> Option<Hold> {
+    let key = DataKey::Hold(hold_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Check if a hold still applies at the current ledger
+pub fn is_hold_active(env: &Env, hold: &Hold) -> bool {
+    match hold.expiration_ledger {
+        Some(expiration) => expiration >= env.ledger().sequence(),
+        None => true,
+    }
+}
+
+/// Get the IDs of all holds on an account, including expired ones
+pub fn get_account_holds(env: &Env, account: &Address) -> Vec<String> {
+    let key = DataKey::AccountHolds(account.clone());
+    env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Store the hold IDs of an account
+fn set_account_holds(env: &Env, account: &Address, hold_ids: &Vec<String>) {
+    let key = DataKey::AccountHolds(account.clone());
+    if hold_ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, hold_ids);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Store a new hold and add it to its account's hold list
+pub fn place_hold(env: &Env, hold_id: &String, hold: &Hold) {
+    let key = DataKey::Hold(hold_id.clone());
+    env.storage().persistent().set(&key, hold);
     extend_persistent_ttl(env, &key);
+
+    let mut hold_ids = get_account_holds(env, &hold.account);
+    hold_ids.push_back(hold_id.clone());
+    set_account_holds(env, &hold.account, &hold_ids);
 }
 
-/// Unfreeze account
-pub fn unfreeze_account(env: &Env, account: &Address) {
-    let key = DataKey::Frozen(account.clone());
-    env.storage().persistent().remove(&key);
+/// Remove a hold and drop it from its account's hold list
+pub fn remove_hold(env: &Env, hold_id: &String, account: &Address) {
+    env.storage().persistent().remove(&DataKey::Hold(hold_id.clone()));
+
+    let mut hold_ids = get_account_holds(env, account);
+    if let Some(index) = hold_ids.first_index_of(hold_id) {
+        hold_ids.remove(index);
+        set_account_holds(env, account, &hold_ids);
+    }
+}
+
+/// Remove expired holds of an account so they stop counting towards its limit
+pub fn prune_expired_holds(env: &Env, account: &Address) {
+    for hold_id in get_account_holds(env, account).iter() {
+        if let Some(hold) = get_hold(env, &hold_id) {
+            if !is_hold_active(env, &hold) {
+                remove_hold(env, &hold_id, account);
+            }
+        }
+    }
+}
+
+/// Total amount held on an account by active holds
+pub fn get_held_balance(env: &Env, account: &Address) -> i128 {
+    let mut held: i128 = 0;
+    for hold_id in get_account_holds(env, account).iter() {
+        if let Some(hold) = get_hold(env, &hold_id) {
+            if is_hold_active(env, &hold) {
+                held = held.saturating_add(hold.amount);
+            }
+        }
+    }
+    held
+}
+
+/// Balance not encumbered by active holds
+pub fn get_spendable_balance(env: &Env, account: &Address) -> i128 {
+    let spendable = get_balance(env, account) - get_held_balance(env, account);
+    spendable.max(0)
+}
+
+// =============================================================================
+// KYC OPERATIONS
+// =============================================================================
+
+/// Get the KYC level of an account
+pub fn get_kyc_level(env: &Env, account: &Address) -> KycLevel {
+    let key = DataKey::Kyc(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(KycLevel::None)
+}
+
+/// Set the KYC level of an account (removing the entry for `None`)
+pub fn set_kyc_level(env: &Env, account: &Address, level: KycLevel) {
+    let key = DataKey::Kyc(account.clone());
+    if level == KycLevel::None {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &level);
+        extend_persistent_ttl(env, &key);
+    }
+}
+
+/// Get the limits for a KYC level, falling back to the defaults
+pub fn get_tier_limits(env: &Env, level: KycLevel) -> TierLimits {
+    let key = DataKey::KycTier(level);
+    if let Some(limits) = env.storage().persistent().get::<_, TierLimits>(&key) {
+        extend_persistent_ttl(env, &key);
+        return limits;
+    }
+    match level {
+        KycLevel::None => TierLimits {
+            max_balance: 0,
+            max_transfer: 0,
+        },
+        KycLevel::Unverified => TierLimits {
+            max_balance: DEFAULT_UNVERIFIED_MAX_BALANCE,
+            max_transfer: DEFAULT_UNVERIFIED_MAX_TRANSFER,
+        },
+        KycLevel::Basic => TierLimits {
+            max_balance: DEFAULT_BASIC_MAX_BALANCE,
+            max_transfer: DEFAULT_BASIC_MAX_TRANSFER,
+        },
+        KycLevel::Full => TierLimits {
+            max_balance: MAX_SUPPLY,
+            max_transfer: MAX_SUPPLY,
+        },
+    }
+}
+
+/// Set the limits for a KYC level
+pub fn set_tier_limits(env: &Env, level: KycLevel, limits: &TierLimits) {
+    let key = DataKey::KycTier(level);
+    env.storage().persistent().set(&key, limits);
+    extend_persistent_ttl(env, &key);
 }
 
 // =============================================================================
 // REQUEST TRACKING
 // =============================================================================
 
-/// Check if mint request exists
+/// Check if a mint request ID was ever used
+///
+/// Records stored before the used ID set existed are found by their key.
 pub fn mint_request_exists(env: &Env, request_id: &String) -> bool {
+    if is_request_id_used(env, RequestKind::Mint, request_id) {
+        return true;
+    }
     let key = DataKey::MintReq(request_id.clone());
     env.storage().persistent().has(&key)
 }
 
-/// Store mint request
+/// Store mint request, appending new requests to the history indexes
+///
+/// The record is kept for the maximum TTL so the mint can still be reversed
+/// when a fraudulent deposit surfaces weeks later.
 pub fn store_mint_request(env: &Env, request_id: &String, request: &MintRequest) {
     let key = DataKey::MintReq(request_id.clone());
+    if !env.storage().persistent().has(&key) {
+        index_mint_request(env, request_id, request);
+    }
     env.storage().persistent().set(&key, request);
-    extend_persistent_ttl(env, &key);
+    extend_persistent_ttl_max(env, &key);
+}
+
+/// Append a mint request to the history, account and used ID indexes
+pub fn index_mint_request(env: &Env, request_id: &String, request: &MintRequest) {
+    let sequence = get_mint_request_count(env);
+    let slot = DataKey::MintReqIdx(sequence);
+    env.storage().persistent().set(&slot, request_id);
+    extend_persistent_ttl(env, &slot);
+    env.storage().instance().set(&KEY_MINT_REQ_COUNT, &(sequence + 1));
+    mark_request_id_used(env, RequestKind::Mint, request_id);
+
+    let entry = RequestRef {
+        kind: RequestKind::Mint,
+        request_id: request_id.clone(),
+        sequence,
+    };
+    append_account_request(env, &request.to, &entry);
+    if request.minter != request.to {
+        append_account_request(env, &request.minter, &entry);
+    }
 }
 
 /// Get mint request
@@ -372,25 +1370,277 @@ pub fn get_mint_request(env: &Env, request_id: &String) -> Option<MintRequest> {
     env.storage().persistent().get(&key)
 }
 
-/// Check if burn request exists
+/// Check if a burn request ID was ever used
+///
+/// Records stored before the used ID set existed are found by their key.
 pub fn burn_request_exists(env: &Env, request_id: &String) -> bool {
+    if is_request_id_used(env, RequestKind::Burn, request_id) {
+        return true;
+    }
     let key = DataKey::BurnReq(request_id.clone());
     env.storage().persistent().has(&key)
 }
 
-/// Store burn request
+/// Store burn request, appending new requests to the history indexes
 pub fn store_burn_request(env: &Env, request_id: &String, request: &BurnRequest) {
     let key = DataKey::BurnReq(request_id.clone());
+    if !env.storage().persistent().has(&key) {
+        index_burn_request(env, request_id, request);
+    }
     env.storage().persistent().set(&key, request);
     extend_persistent_ttl(env, &key);
 }
 
+/// Append a burn request to the history, account and used ID indexes
+pub fn index_burn_request(env: &Env, request_id: &String, request: &BurnRequest) {
+    let sequence = get_burn_request_count(env);
+    let slot = DataKey::BurnReqIdx(sequence);
+    env.storage().persistent().set(&slot, request_id);
+    extend_persistent_ttl(env, &slot);
+    env.storage().instance().set(&KEY_BURN_REQ_COUNT, &(sequence + 1));
+    mark_request_id_used(env, RequestKind::Burn, request_id);
+
+    let entry = RequestRef {
+        kind: RequestKind::Burn,
+        request_id: request_id.clone(),
+        sequence,
+    };
+    append_account_request(env, &request.from, &entry);
+    if request.operator != request.from {
+        append_account_request(env, &request.operator, &entry);
+    }
+}
+
 /// Get burn request
 pub fn get_burn_request(env: &Env, request_id: &String) -> Option<BurnRequest> {
     let key = DataKey::BurnReq(request_id.clone());
     env.storage().persistent().get(&key)
 }
 
+/// Get a pending mint reservation
+pub fn get_mint_reservation(env: &Env, request_id: &String) -> Option<MintReservation> {
+    let key = DataKey::MintRsv(request_id.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Check if a mint reservation is pending under this request ID
+pub fn mint_reservation_exists(env: &Env, request_id: &String) -> bool {
+    let key = DataKey::MintRsv(request_id.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Store a pending mint reservation
+///
+/// The entry lives at least until its `confirm_by_ledger`, which callers
+/// keep within `max_ttl` of the current ledger.
+pub fn store_mint_reservation(env: &Env, request_id: &String, reservation: &MintReservation) {
+    let key = DataKey::MintRsv(request_id.clone());
+    env.storage().persistent().set(&key, reservation);
+    extend_persistent_ttl_until(env, &key, reservation.confirm_by_ledger);
+}
+
+/// Remove a mint reservation once confirmed or voided
+pub fn remove_mint_reservation(env: &Env, request_id: &String) {
+    let key = DataKey::MintRsv(request_id.clone());
+    env.storage().persistent().remove(&key);
+}
+
+/// Get the supply reserved by pending two-phase mints
+pub fn get_reserved_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&KEY_RESERVED_SUPPLY)
+        .unwrap_or(0)
+}
+
+/// Set the supply reserved by pending two-phase mints
+pub fn set_reserved_supply(env: &Env, amount: i128) {
+    env.storage().instance().set(&KEY_RESERVED_SUPPLY, &amount);
+}
+
+// =============================================================================
+// USED REQUEST IDS - Replay protection independent of request record TTL
+// =============================================================================
+//
+// Request records expire with their TTL (30 days for burns, the network
+// maximum for mints), which must not make a bank reference mintable again.
+// Every used ID is hashed with its request kind into one of
+// `REQUEST_ID_BUCKETS` buckets. A bucket is a chain of shards
+// holding at most `REQUEST_IDS_PER_SHARD` hashes each, so no entry outgrows
+// the ledger entry size limit; only the last shard of a chain is not full.
+//
+// Shards are extended to the maximum TTL when written. Shards that receive
+// no new IDs must be kept alive by calling `extend_request_id_buckets` over
+// every bucket at least once per maximum TTL (a keeper job).
+
+/// Hash a request ID with its kind into the used ID set
+pub fn request_id_hash(env: &Env, kind: RequestKind, request_id: &String) -> BytesN<32> {
+    let mut payload = Bytes::new(env);
+    payload.push_back(kind as u8);
+    payload.append(&request_id.clone().to_xdr(env));
+    env.crypto().sha256(&payload).to_bytes()
+}
+
+/// Bucket holding a request ID hash (its first 12 bits)
+pub fn request_id_bucket(hash: &BytesN<32>) -> u32 {
+    let bytes = hash.to_array();
+    ((bytes[0] as u32) << 4) | ((bytes[1] as u32) >> 4)
+}
+
+/// Get a shard of a used request ID bucket
+fn get_request_id_shard(env: &Env, bucket: u32, shard: u32) -> Option<Map<BytesN<32>, bool>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ReqIdShard(bucket, shard))
+}
+
+/// Check if a request ID of this kind is in the used ID set
+pub fn is_request_id_used(env: &Env, kind: RequestKind, request_id: &String) -> bool {
+    let hash = request_id_hash(env, kind, request_id);
+    let bucket = request_id_bucket(&hash);
+    let mut shard = 0;
+    while let Some(hashes) = get_request_id_shard(env, bucket, shard) {
+        if hashes.contains_key(hash.clone()) {
+            return true;
+        }
+        if hashes.len() < REQUEST_IDS_PER_SHARD {
+            return false;
+        }
+        shard += 1;
+    }
+    false
+}
+
+/// Add a request ID of this kind to the used ID set
+pub fn mark_request_id_used(env: &Env, kind: RequestKind, request_id: &String) {
+    let hash = request_id_hash(env, kind, request_id);
+    let bucket = request_id_bucket(&hash);
+    let mut shard = 0;
+    loop {
+        let mut hashes = match get_request_id_shard(env, bucket, shard) {
+            Some(hashes) if hashes.contains_key(hash.clone()) => return,
+            Some(hashes) if hashes.len() >= REQUEST_IDS_PER_SHARD => {
+                shard += 1;
+                continue;
+            }
+            Some(hashes) => hashes,
+            None => Map::new(env),
+        };
+        hashes.set(hash, true);
+        let key = DataKey::ReqIdShard(bucket, shard);
+        env.storage().persistent().set(&key, &hashes);
+        extend_persistent_ttl_max(env, &key);
+        return;
+    }
+}
+
+/// Extend every shard of the used request ID buckets `start..end`
+pub fn extend_request_id_buckets(env: &Env, start: u32, end: u32) {
+    for bucket in start..end.min(REQUEST_ID_BUCKETS) {
+        let mut shard = 0;
+        while let Some(hashes) = get_request_id_shard(env, bucket, shard) {
+            extend_persistent_ttl_max(env, &DataKey::ReqIdShard(bucket, shard));
+            if hashes.len() < REQUEST_IDS_PER_SHARD {
+                break;
+            }
+            shard += 1;
+        }
+    }
+}
+
+/// Number of mint requests in the history
+pub fn get_mint_request_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&KEY_MINT_REQ_COUNT)
+        .unwrap_or(0)
+}
+
+/// Number of burn requests in the history
+pub fn get_burn_request_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&KEY_BURN_REQ_COUNT)
+        .unwrap_or(0)
+}
+
+/// Get the mint request at a position of the history, extending its record
+pub fn get_mint_request_at(env: &Env, sequence: u32) -> Option<MintRequest> {
+    let slot = DataKey::MintReqIdx(sequence);
+    let request_id: String = env.storage().persistent().get(&slot)?;
+    extend_persistent_ttl_max(env, &slot);
+    extend_persistent_ttl_max(env, &DataKey::MintReq(request_id.clone()));
+    get_mint_request(env, &request_id)
+}
+
+/// Get the burn request at a position of the history
+pub fn get_burn_request_at(env: &Env, sequence: u32) -> Option<BurnRequest> {
+    let request_id: String = env.storage().persistent().get(&DataKey::BurnReqIdx(sequence))?;
+    get_burn_request(env, &request_id)
+}
+
+/// Number of requests in an account's history
+pub fn get_account_request_count(env: &Env, account: &Address) -> u32 {
+    let key = DataKey::AcctReqCount(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Get the entry at a position of an account's request history
+pub fn get_account_request_at(env: &Env, account: &Address, index: u32) -> Option<RequestRef> {
+    let key = DataKey::AcctReqIdx(account.clone(), index);
+    env.storage().persistent().get(&key)
+}
+
+/// Append an entry to an account's request history
+fn append_account_request(env: &Env, account: &Address, entry: &RequestRef) {
+    let index = get_account_request_count(env, account);
+    let slot = DataKey::AcctReqIdx(account.clone(), index);
+    env.storage().persistent().set(&slot, entry);
+    extend_persistent_ttl(env, &slot);
+
+    let count_key = DataKey::AcctReqCount(account.clone());
+    env.storage().persistent().set(&count_key, &(index + 1));
+    extend_persistent_ttl(env, &count_key);
+}
+
+/// Check if a clawback with this legal reference exists
+pub fn clawback_exists(env: &Env, legal_ref: &String) -> bool {
+    let key = DataKey::Clawback(legal_ref.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Store clawback record
+pub fn store_clawback(env: &Env, legal_ref: &String, record: &ClawbackRecord) {
+    let key = DataKey::Clawback(legal_ref.clone());
+    env.storage().persistent().set(&key, record);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Get clawback record
+pub fn get_clawback(env: &Env, legal_ref: &String) -> Option<ClawbackRecord> {
+    let key = DataKey::Clawback(legal_ref.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Check if a mint request was reversed
+pub fn mint_reversal_exists(env: &Env, request_id: &String) -> bool {
+    let key = DataKey::MintRev(request_id.clone());
+    env.storage().persistent().has(&key)
+}
+
+/// Store mint reversal record
+pub fn store_mint_reversal(env: &Env, request_id: &String, reversal: &MintReversal) {
+    let key = DataKey::MintRev(request_id.clone());
+    env.storage().persistent().set(&key, reversal);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Get mint reversal record
+pub fn get_mint_reversal(env: &Env, request_id: &String) -> Option<MintReversal> {
+    let key = DataKey::MintRev(request_id.clone());
+    env.storage().persistent().get(&key)
+}
+
 // =============================================================================
 // CONTRACT STATE
 // =============================================================================
@@ -416,17 +1666,154 @@ pub fn set_owner(env: &Env, owner: &Address) {
     env.storage().instance().set(&KEY_OWNER, owner);
 }
 
-/// Check if contract is paused
+/// Remove contract owner (renounce)
+pub fn remove_owner(env: &Env) {
+    env.storage().instance().remove(&KEY_OWNER);
+}
+
+/// Get pending ownership offer
+pub fn get_pending_owner(env: &Env) -> Option<PendingOwner> {
+    env.storage().instance().get(&KEY_PENDING_OWNER)
+}
+
+/// Set pending ownership offer
+pub fn set_pending_owner(env: &Env, pending: &PendingOwner) {
+    env.storage().instance().set(&KEY_PENDING_OWNER, pending);
+}
+
+/// Remove pending ownership offer
+pub fn remove_pending_owner(env: &Env) {
+    env.storage().instance().remove(&KEY_PENDING_OWNER);
+}
+
+/// Get scheduled upgrade
+pub fn get_pending_upgrade(env: &Env) -> Option<PendingUpgrade> {
+    env.storage().instance().get(&KEY_PENDING_UPGRADE)
+}
+
+/// Set scheduled upgrade
+pub fn set_pending_upgrade(env: &Env, pending: &PendingUpgrade) {
+    env.storage().instance().set(&KEY_PENDING_UPGRADE, pending);
+}
+
+/// Remove scheduled upgrade
+pub fn remove_pending_upgrade(env: &Env) {
+    env.storage().instance().remove(&KEY_PENDING_UPGRADE);
+}
+
+/// Get upgrade timelock delay in ledgers
+pub fn get_upgrade_delay(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&KEY_UPGRADE_DELAY)
+        .unwrap_or(DEFAULT_UPGRADE_DELAY)
+}
+
+/// Set upgrade timelock delay in ledgers
+pub fn set_upgrade_delay(env: &Env, delay: u32) {
+    env.storage().instance().set(&KEY_UPGRADE_DELAY, &delay);
+}
+
+/// Get the pause bitmask, mapping the legacy boolean to `PAUSE_ALL`
+pub fn get_pause_flags(env: &Env) -> u32 {
+    if let Some(flags) = env.storage().instance().get(&KEY_PAUSE_FLAGS) {
+        return flags;
+    }
+    if env.storage().instance().get(&KEY_PAUSED).unwrap_or(false) {
+        PAUSE_ALL
+    } else {
+        0
+    }
+}
+
+/// Set the pause bitmask, dropping the legacy boolean
+pub fn set_pause_flags(env: &Env, flags: u32) {
+    env.storage().instance().set(&KEY_PAUSE_FLAGS, &flags);
+    env.storage().instance().remove(&KEY_PAUSED);
+}
+
+/// Check if any operation class is paused
 pub fn is_paused(env: &Env) -> bool {
-    env.storage().instance().get(&KEY_PAUSED).unwrap_or(false)
+    get_pause_flags(env) != 0
 }
 
-/// Set paused state
-pub fn set_paused(env: &Env, paused: bool) {
-    env.storage().instance().set(&KEY_PAUSED, &paused);
+/// Get contract configuration, falling back to the production defaults
+pub fn get_config(env: &Env) -> ContractConfig {
+    env.storage()
+        .instance()
+        .get(&KEY_CONFIG)
+        .unwrap_or(ContractConfig {
+            max_mint_amount: DEFAULT_MAX_MINT,
+            max_burn_amount: DEFAULT_MAX_BURN,
+            min_transfer_amount: MIN_TRANSFER,
+            require_whitelist: false,
+        })
+}
+
+/// Set contract configuration
+pub fn set_config(env: &Env, config: &ContractConfig) {
+    env.storage().instance().set(&KEY_CONFIG, config);
+}
+
+/// Get the transfer fee configuration (None when fees are disabled)
+pub fn get_fee_config(env: &Env) -> Option<FeeConfig> {
+    env.storage().instance().get(&KEY_FEE_CONFIG)
+}
+
+/// Set or clear the transfer fee configuration
+pub fn set_fee_config(env: &Env, config: &Option<FeeConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&KEY_FEE_CONFIG, config),
+        None => env.storage().instance().remove(&KEY_FEE_CONFIG),
+    }
+}
+
+/// Check if an account is exempt from transfer fees
+pub fn is_fee_exempt(env: &Env, account: &Address) -> bool {
+    let key = DataKey::FeeExempt(account.clone());
+    env.storage().persistent().get(&key).unwrap_or(false)
+}
+
+/// Add or remove an account's transfer fee exemption
+pub fn set_fee_exempt(env: &Env, account: &Address, exempt: bool) {
+    let key = DataKey::FeeExempt(account.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+        extend_persistent_ttl(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Get the proof-of-reserves configuration (None when the gate is off)
+pub fn get_reserve_config(env: &Env) -> Option<ReserveConfig> {
+    env.storage().instance().get(&KEY_RESERVE_CONFIG)
+}
+
+/// Set or clear the proof-of-reserves configuration
+pub fn set_reserve_config(env: &Env, config: &Option<ReserveConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&KEY_RESERVE_CONFIG, config),
+        None => env.storage().instance().remove(&KEY_RESERVE_CONFIG),
+    }
+}
+
+/// Get the latest reserve attestation
+pub fn get_reserve_attestation(env: &Env) -> Option<ReserveAttestation> {
+    env.storage().instance().get(&KEY_RESERVE_ATTESTATION)
+}
+
+/// Replace the latest reserve attestation
+pub fn set_reserve_attestation(env: &Env, attestation: &ReserveAttestation) {
+    env.storage().instance().set(&KEY_RESERVE_ATTESTATION, attestation);
 }
 
 /// Get contract version
 pub fn get_version(env: &Env) -> u32 {
     env.storage().instance().get(&KEY_VERSION).unwrap_or(0)
 }
+
+/// Set contract version
+pub fn set_version(env: &Env, version: u32) {
+    env.storage().instance().set(&KEY_VERSION, &version);
+}
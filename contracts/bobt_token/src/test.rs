@@ -5,15 +5,55 @@
 //! Tests organized by functionality:
 //! - Initialization
 //! - Token operations (mint, transfer, burn)
-//! - Pause functionality
+//! - Pause functionality (pause-all and per operation class)
 //! - Freeze/Blacklist functionality
 //! - Access control
 //! - Request tracking
 //! - Allowance and expiration
+//! - Contract configuration limits
+//! - KYC tiers (permissioned mode)
+//! - Legacy mint removal and migration
+//! - Minter allowances
+//! - Two-step ownership transfer
+//! - Role enumeration and role admins
+//! - Time-bound role grants
+//! - Timelocked upgrades
+//! - Partial clawback and seizure
+//! - Mint reversal (chargeback)
+//! - Freeze records, expiry and enumeration
+//! - Balance holds
+//! - Signed permit approvals
+//! - Relayed transfers with authorization
+//! - Transfer fees and exemptions
+//! - Batch transfers and their resource budget
+//! - Transfers with payment memos
+//! - Two-phase (reserve/confirm/void) mints
+//! - Historical balance and supply checkpoints
+//! - Proof-of-reserves gate on minting
+//! - SEP-41 event conformance
 
 use crate::contract::BOBTTokenClient;
-use crate::BOBTToken;
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use crate::storage::{
+    self, Checkpoint, DataKey, CONTRACT_VERSION, DEFAULT_MAX_BURN, DEFAULT_MAX_MINT,
+    DEFAULT_UPGRADE_DELAY, KEY_PAUSED, KEY_PAUSE_FLAGS, KEY_SUPPLY_CKPT_COUNT, MAX_BACKFILL_BATCH,
+    MAX_BALANCE_CHECKPOINTS, MAX_BATCH_SIZE, MAX_BUCKETS_PER_EXTEND, MAX_MEMO_LEN,
+    MAX_REQUEST_BACKFILL_BATCH, MAX_REQUEST_ID_LEN, MAX_SUPPLY, MAX_SUPPLY_CHECKPOINTS,
+    MAX_UPGRADE_DELAY, MIN_TRANSFER, MIN_UPGRADE_DELAY, PAUSE_ALL, PAUSE_APPROVE, PAUSE_BURN,
+    PAUSE_MINT, PAUSE_TRANSFER, REQUEST_IDS_PER_SHARD, REQUEST_ID_BUCKETS, ROLE_BLACKLISTER,
+    ROLE_MINTER, ROLE_PAUSER,
+};
+use crate::{
+    signature, BOBTError, BOBTToken, BurnRequest, ContractConfig, FeeConfig, FreezeReason,
+    KycLevel, MintRequest, RequestKind, RequestRef, ReserveConfig, TierLimits,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    vec,
+    xdr::FromXdr,
+    Address, Bytes, BytesN, Env, IntoVal, InvokeError, Map, String, Symbol, Vec,
+};
 
 // =============================================================================
 // TEST HELPERS
@@ -34,13 +74,21 @@ fn setup() -> (Env, Address, BOBTTokenClient<'static>) {
     (env, owner, client)
 }
 
-/// Setup with minter role granted
+/// Setup with minter role granted and an allowance covering the full supply
 fn setup_with_minter() -> (Env, Address, BOBTTokenClient<'static>) {
     let (env, owner, client) = setup();
-    client.grant_role(&owner, &symbol_short!("minter"), &owner);
+    client.grant_role(&owner, &symbol_short!("minter"), &owner, &None);
+    client.grant_role(&owner, &symbol_short!("mstrmint"), &owner, &None);
+    client.configure_minter(&owner, &owner, &MAX_SUPPLY);
     (env, owner, client)
 }
 
+/// Freeze an account indefinitely under a generic reason code
+fn freeze(env: &Env, client: &BOBTTokenClient, blacklister: &Address, account: &Address) {
+    let case_ref = String::from_str(env, "CASE-0");
+    client.freeze(blacklister, account, &FreezeReason::Other, &case_ref, &None);
+}
+
 // =============================================================================
 // INITIALIZATION TESTS
 // =============================================================================
@@ -62,7 +110,7 @@ fn test_initialize() {
 #[test]
 fn test_version() {
     let (_env, _owner, client) = setup();
-    assert_eq!(client.version(), 1);
+    assert_eq!(client.version(), CONTRACT_VERSION);
 }
 
 #[test]
@@ -197,7 +245,7 @@ fn test_pause_unpause() {
     let (_env, owner, client) = setup();
 
     // Grant pauser role
-    client.grant_role(&owner, &symbol_short!("pauser"), &owner);
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
 
     // Pause
     client.pause(&owner);
@@ -216,7 +264,7 @@ fn test_cannot_transfer_when_paused() {
     let bob = Address::generate(&env);
 
     // Grant pauser role
-    client.grant_role(&owner, &symbol_short!("pauser"), &owner);
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
 
     // Mint tokens
     let request_id = String::from_str(&env, "REQ004");
@@ -236,7 +284,7 @@ fn test_cannot_mint_when_paused() {
     let recipient = Address::generate(&env);
 
     // Grant pauser role
-    client.grant_role(&owner, &symbol_short!("pauser"), &owner);
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
 
     // Pause
     client.pause(&owner);
@@ -246,6 +294,98 @@ fn test_cannot_mint_when_paused() {
     client.admin_mint(&owner, &recipient, &1000_0000000, &request_id);
 }
 
+#[test]
+fn test_pause_mint_only() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "PF_MINT"));
+
+    client.pause_operations(&owner, &PAUSE_MINT);
+    assert_eq!(client.pause_flags(), PAUSE_MINT);
+    assert!(client.is_paused());
+
+    let request_id = String::from_str(&env, "PF_BLOCKED");
+    assert_eq!(
+        client.try_admin_mint(&owner, &alice, &1_0000000, &request_id),
+        Err(Ok(BOBTError::ContractPaused))
+    );
+
+    // Users can still transfer, approve and redeem
+    client.transfer(&alice, &bob, &100_0000000);
+    client.approve(&alice, &bob, &100_0000000, &1000);
+    client.burn(&alice, &100_0000000);
+    assert_eq!(client.balance(&alice), 800_0000000);
+}
+
+#[test]
+fn test_pause_flags_events_and_unpause() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
+
+    client.pause_operations(&owner, &(PAUSE_TRANSFER | PAUSE_BURN));
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "pause"), PAUSE_TRANSFER).into_val(&env),
+                owner.clone().into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "pause"), PAUSE_BURN).into_val(&env),
+                owner.clone().into_val(&env),
+            ),
+        ]
+    );
+
+    // Pause-all only reports the classes it actually paused
+    client.pause(&owner);
+    assert_eq!(env.events().all().len(), 2);
+    assert_eq!(client.pause_flags(), PAUSE_ALL);
+    assert_eq!(client.try_pause(&owner), Err(Ok(BOBTError::ContractPaused)));
+
+    client.unpause_operations(&owner, &PAUSE_BURN);
+    assert_eq!(client.pause_flags(), PAUSE_TRANSFER | PAUSE_MINT | PAUSE_APPROVE);
+
+    client.unpause(&owner);
+    assert_eq!(env.events().all().len(), 3);
+    assert_eq!(client.pause_flags(), 0);
+    assert_eq!(client.try_unpause(&owner), Err(Ok(BOBTError::ContractNotPaused)));
+}
+
+#[test]
+fn test_pause_rejects_unknown_flags() {
+    let (_env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
+
+    assert_eq!(
+        client.try_pause_operations(&owner, &0),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_pause_operations(&owner, &16),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+}
+
+#[test]
+fn test_legacy_paused_flag_pauses_everything() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
+    env.as_contract(&client.address, || {
+        env.storage().instance().remove(&KEY_PAUSE_FLAGS);
+        env.storage().instance().set(&KEY_PAUSED, &true);
+    });
+
+    assert_eq!(client.pause_flags(), PAUSE_ALL);
+    client.unpause(&owner);
+    assert!(!client.is_paused());
+}
+
 // =============================================================================
 // FREEZE/BLACKLIST TESTS
 // =============================================================================
@@ -256,10 +396,10 @@ fn test_freeze_unfreeze() {
     let bad_actor = Address::generate(&env);
 
     // Grant blacklister role
-    client.grant_role(&owner, &symbol_short!("blklst"), &owner);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
 
     // Freeze
-    client.freeze(&owner, &bad_actor);
+    freeze(&env, &client, &owner, &bad_actor);
     assert!(client.is_frozen(&bad_actor));
 
     // Unfreeze
@@ -275,14 +415,14 @@ fn test_cannot_transfer_from_frozen_account() {
     let bob = Address::generate(&env);
 
     // Grant blacklister role
-    client.grant_role(&owner, &symbol_short!("blklst"), &owner);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
 
     // Mint tokens
     let request_id = String::from_str(&env, "REQ005");
     client.admin_mint(&owner, &alice, &1000_0000000, &request_id);
 
     // Freeze alice
-    client.freeze(&owner, &alice);
+    freeze(&env, &client, &owner, &alice);
 
     // Try to transfer from frozen account - should fail
     client.transfer(&alice, &bob, &100_0000000);
@@ -296,14 +436,14 @@ fn test_cannot_transfer_to_frozen_account() {
     let frozen_bob = Address::generate(&env);
 
     // Grant blacklister role
-    client.grant_role(&owner, &symbol_short!("blklst"), &owner);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
 
     // Mint tokens
     let request_id = String::from_str(&env, "REQ_TO_FROZEN");
     client.admin_mint(&owner, &alice, &1000_0000000, &request_id);
 
     // Freeze bob
-    client.freeze(&owner, &frozen_bob);
+    freeze(&env, &client, &owner, &frozen_bob);
 
     // Try to transfer to frozen account - should fail
     client.transfer(&alice, &frozen_bob, &100_0000000);
@@ -315,7 +455,7 @@ fn test_clawback() {
     let bad_actor = Address::generate(&env);
 
     // Grant blacklister role
-    client.grant_role(&owner, &symbol_short!("blklst"), &owner);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
 
     // Mint tokens
     let request_id = String::from_str(&env, "REQ006");
@@ -323,7 +463,7 @@ fn test_clawback() {
     assert_eq!(client.balance(&bad_actor), 500_0000000);
 
     // Freeze and destroy funds
-    client.freeze(&owner, &bad_actor);
+    freeze(&env, &client, &owner, &bad_actor);
     client.destroy_frozen_funds(&owner, &bad_actor);
 
     assert_eq!(client.balance(&bad_actor), 0);
@@ -337,7 +477,7 @@ fn test_cannot_clawback_unfrozen_account() {
     let alice = Address::generate(&env);
 
     // Grant blacklister role
-    client.grant_role(&owner, &symbol_short!("blklst"), &owner);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
 
     // Mint tokens
     let request_id = String::from_str(&env, "REQ_CLAWBACK");
@@ -360,7 +500,7 @@ fn test_role_management() {
     assert!(!client.has_role(&symbol_short!("minter"), &minter));
 
     // Grant minter role
-    client.grant_role(&owner, &symbol_short!("minter"), &minter);
+    client.grant_role(&owner, &symbol_short!("minter"), &minter, &None);
     assert!(client.has_role(&symbol_short!("minter"), &minter));
 
     // Revoke minter role
@@ -398,7 +538,7 @@ fn test_unauthorized_freeze() {
     let victim = Address::generate(&env);
 
     // Try to freeze without blacklister role
-    client.freeze(&attacker, &victim);
+    freeze(&env, &client, &attacker, &victim);
 }
 
 #[test]
@@ -406,11 +546,14 @@ fn test_transfer_ownership() {
     let (env, owner, client) = setup();
     let new_owner = Address::generate(&env);
 
-    // Transfer ownership
-    client.transfer_ownership(&owner, &new_owner);
+    // Propose and accept ownership
+    client.propose_ownership(&owner, &new_owner, &None);
+    assert_eq!(client.owner(), owner);
+    client.accept_ownership(&new_owner);
 
     // Verify new owner
     assert_eq!(client.owner(), new_owner);
+    assert!(client.pending_owner().is_none());
     assert!(client.has_role(&symbol_short!("owner"), &new_owner));
     assert!(!client.has_role(&symbol_short!("owner"), &owner));
 }
@@ -423,10 +566,11 @@ fn test_old_owner_cannot_grant_roles() {
     let minter = Address::generate(&env);
 
     // Transfer ownership
-    client.transfer_ownership(&owner, &new_owner);
+    client.propose_ownership(&owner, &new_owner, &None);
+    client.accept_ownership(&new_owner);
 
     // Old owner tries to grant role - should fail
-    client.grant_role(&owner, &symbol_short!("minter"), &minter);
+    client.grant_role(&owner, &symbol_short!("minter"), &minter, &None);
 }
 
 // =============================================================================
@@ -491,6 +635,172 @@ fn test_get_nonexistent_request() {
     client.get_mint_request(&request_id);
 }
 
+#[test]
+fn test_request_id_replay_blocked_after_record_expires() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-2024-000001");
+    client.admin_mint(&owner, &recipient, &100_0000000, &request_id);
+
+    // Simulate the request record lapsing out of storage
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::MintReq(request_id.clone()));
+    });
+
+    assert!(client.mint_request_exists(&request_id));
+    assert_eq!(
+        client.try_admin_mint(&owner, &recipient, &100_0000000, &request_id),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(client.balance(&recipient), 100_0000000);
+
+    // Mint and burn IDs are tracked separately
+    assert!(!client.burn_request_exists(&request_id));
+    client.admin_burn(&owner, &recipient, &10_0000000, &request_id);
+    assert!(client.burn_request_exists(&request_id));
+}
+
+#[test]
+fn test_full_request_id_shard_spills_into_next() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-2024-000002");
+    let hash = storage::request_id_hash(&env, RequestKind::Mint, &request_id);
+    let bucket = storage::request_id_bucket(&hash);
+
+    // Fill the first shard of the ID's bucket with other hashes
+    env.as_contract(&client.address, || {
+        let mut hashes = Map::new(&env);
+        for i in 0..REQUEST_IDS_PER_SHARD {
+            hashes.set(BytesN::from_array(&env, &[i as u8; 32]), true);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReqIdShard(bucket, 0), &hashes);
+    });
+
+    client.admin_mint(&owner, &recipient, &100_0000000, &request_id);
+
+    let (first, second) = env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        let first: Map<BytesN<32>, bool> = storage.get(&DataKey::ReqIdShard(bucket, 0)).unwrap();
+        let second: Map<BytesN<32>, bool> = storage.get(&DataKey::ReqIdShard(bucket, 1)).unwrap();
+        (first, second)
+    });
+    assert_eq!(first.len(), REQUEST_IDS_PER_SHARD);
+    assert!(second.contains_key(hash));
+    assert!(client.mint_request_exists(&request_id));
+}
+
+#[test]
+fn test_request_id_buckets_need_keeper_extension() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-2024-000003");
+    let hash = storage::request_id_hash(&env, RequestKind::Mint, &request_id);
+    let key = DataKey::ReqIdShard(storage::request_id_bucket(&hash), 0);
+    client.admin_mint(&owner, &recipient, &100_0000000, &request_id);
+
+    let ttl = || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&key));
+    let written = ttl();
+
+    // An idle bucket only ages; a keeper sweep restores its full TTL
+    env.ledger().with_mut(|li| li.sequence_number += 100_000);
+    assert_eq!(ttl(), written - 100_000);
+    for start in (0..REQUEST_ID_BUCKETS).step_by(MAX_BUCKETS_PER_EXTEND as usize) {
+        client.extend_request_id_buckets(&start, &MAX_BUCKETS_PER_EXTEND);
+    }
+    assert_eq!(ttl(), written);
+}
+
+#[test]
+fn test_request_id_length_is_validated() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let empty = String::from_str(&env, "");
+    let too_long = String::from_bytes(&env, &[b'R'; MAX_REQUEST_ID_LEN as usize + 1]);
+    let longest = String::from_bytes(&env, &[b'R'; MAX_REQUEST_ID_LEN as usize]);
+
+    assert_eq!(
+        client.try_admin_mint(&owner, &recipient, &100_0000000, &empty),
+        Err(Ok(BOBTError::InvalidRequestId))
+    );
+    assert_eq!(
+        client.try_admin_mint(&owner, &recipient, &100_0000000, &too_long),
+        Err(Ok(BOBTError::InvalidRequestId))
+    );
+    client.admin_mint(&owner, &recipient, &100_0000000, &longest);
+
+    assert_eq!(
+        client.try_admin_burn(&owner, &recipient, &10_0000000, &too_long),
+        Err(Ok(BOBTError::InvalidRequestId))
+    );
+}
+
+#[test]
+fn test_list_mint_and_burn_requests() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "MNT-1"));
+    client.admin_mint(&owner, &bob, &200_0000000, &String::from_str(&env, "MNT-2"));
+    client.admin_mint(&owner, &alice, &300_0000000, &String::from_str(&env, "MNT-3"));
+    client.admin_burn(&owner, &alice, &50_0000000, &String::from_str(&env, "BRN-1"));
+
+    assert_eq!(client.mint_request_count(), 3);
+    assert_eq!(client.burn_request_count(), 1);
+
+    let page = client.list_mint_requests(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().external_ref, String::from_str(&env, "MNT-2"));
+    assert_eq!(page.get(0).unwrap().to, bob);
+    assert_eq!(page.get(1).unwrap().external_ref, String::from_str(&env, "MNT-3"));
+    assert_eq!(client.list_mint_requests(&3, &5).len(), 0);
+
+    let burns = client.list_burn_requests(&0, &10);
+    assert_eq!(burns.len(), 1);
+    assert_eq!(burns.get(0).unwrap().amount, 50_0000000);
+}
+
+#[test]
+fn test_list_requests_for_account() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "MNT-1"));
+    client.admin_mint(&owner, &bob, &200_0000000, &String::from_str(&env, "MNT-2"));
+    client.admin_burn(&owner, &alice, &50_0000000, &String::from_str(&env, "BRN-1"));
+
+    assert_eq!(client.request_count_for(&alice), 2);
+    assert_eq!(
+        client.list_requests_for(&alice, &0, &10),
+        vec![
+            &env,
+            RequestRef {
+                kind: RequestKind::Mint,
+                request_id: String::from_str(&env, "MNT-1"),
+                sequence: 0,
+            },
+            RequestRef {
+                kind: RequestKind::Burn,
+                request_id: String::from_str(&env, "BRN-1"),
+                sequence: 0,
+            }
+        ]
+    );
+
+    // The minter sees every request it executed
+    assert_eq!(client.request_count_for(&owner), 3);
+    let page = client.list_requests_for(&owner, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().request_id, String::from_str(&env, "MNT-2"));
+    assert_eq!(page.get(0).unwrap().sequence, 1);
+}
+
 // =============================================================================
 // EDGE CASE TESTS
 // =============================================================================
@@ -524,9 +834,9 @@ fn test_multiple_roles_same_account() {
     let operator = Address::generate(&env);
 
     // Grant multiple roles to same account
-    client.grant_role(&owner, &symbol_short!("minter"), &operator);
-    client.grant_role(&owner, &symbol_short!("pauser"), &operator);
-    client.grant_role(&owner, &symbol_short!("blklst"), &operator);
+    client.grant_role(&owner, &symbol_short!("minter"), &operator, &None);
+    client.grant_role(&owner, &symbol_short!("pauser"), &operator, &None);
+    client.grant_role(&owner, &symbol_short!("blklst"), &operator, &None);
 
     assert!(client.has_role(&symbol_short!("minter"), &operator));
     assert!(client.has_role(&symbol_short!("pauser"), &operator));
@@ -539,3 +849,3341 @@ fn test_multiple_roles_same_account() {
     assert!(!client.has_role(&symbol_short!("pauser"), &operator));
     assert!(client.has_role(&symbol_short!("blklst"), &operator));
 }
+
+// =============================================================================
+// CONFIGURATION TESTS
+// =============================================================================
+
+/// Build a config with the given limits and whitelist mode disabled
+fn config(max_mint: i128, max_burn: i128, min_transfer: i128) -> ContractConfig {
+    ContractConfig {
+        max_mint_amount: max_mint,
+        max_burn_amount: max_burn,
+        min_transfer_amount: min_transfer,
+        require_whitelist: false,
+    }
+}
+
+#[test]
+fn test_default_config() {
+    let (_env, _owner, client) = setup();
+
+    let config = client.get_config();
+    assert_eq!(config.max_mint_amount, DEFAULT_MAX_MINT);
+    assert_eq!(config.max_burn_amount, DEFAULT_MAX_BURN);
+    assert_eq!(config.min_transfer_amount, MIN_TRANSFER);
+    assert!(!config.require_whitelist);
+}
+
+#[test]
+fn test_set_config() {
+    let (_env, owner, client) = setup();
+
+    client.set_config(&owner, &config(5000_0000000, 2000_0000000, 1_0000000));
+
+    let stored = client.get_config();
+    assert_eq!(stored.max_mint_amount, 5000_0000000);
+    assert_eq!(stored.max_burn_amount, 2000_0000000);
+    assert_eq!(stored.min_transfer_amount, 1_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_unauthorized_set_config() {
+    let (env, _owner, client) = setup();
+    let attacker = Address::generate(&env);
+
+    client.set_config(&attacker, &config(1, 1, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+fn test_invalid_config() {
+    let (_env, owner, client) = setup();
+
+    // Zero max mint is rejected
+    client.set_config(&owner, &config(0, 1_0000000, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")]
+fn test_mint_limit_enforced() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    client.set_config(&owner, &config(1000_0000000, 1000_0000000, 1));
+
+    let request_id = String::from_str(&env, "REQ_MINT_LIMIT");
+    client.admin_mint(&owner, &recipient, &1001_0000000, &request_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #40)")]
+fn test_burn_limit_enforced() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "REQ_BURN_LIMIT"));
+    client.set_config(&owner, &config(1000_0000000, 100_0000000, 1));
+
+    client.burn(&alice, &101_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+fn test_min_transfer_enforced() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "REQ_MIN_TR"));
+    client.set_config(&owner, &config(1000_0000000, 1000_0000000, 1_0000000));
+
+    client.transfer(&alice, &bob, &9999999);
+}
+
+// =============================================================================
+// KYC TIER TESTS
+// =============================================================================
+
+/// Setup with minter and KYC officer roles granted and whitelist mode enabled
+fn setup_permissioned() -> (Env, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("kyc"), &owner, &None);
+    let mut config = client.get_config();
+    config.require_whitelist = true;
+    client.set_config(&owner, &config);
+    (env, owner, client)
+}
+
+#[test]
+fn test_set_kyc_level() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+
+    assert_eq!(client.kyc_level(&alice), KycLevel::None);
+
+    client.set_kyc_level(&owner, &alice, &KycLevel::Basic);
+    assert_eq!(client.kyc_level(&alice), KycLevel::Basic);
+
+    client.set_kyc_level(&owner, &alice, &KycLevel::None);
+    assert_eq!(client.kyc_level(&alice), KycLevel::None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_unauthorized_set_kyc_level() {
+    let (env, _owner, client) = setup_permissioned();
+    let attacker = Address::generate(&env);
+
+    client.set_kyc_level(&attacker, &attacker, &KycLevel::Full);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_cannot_mint_to_unapproved_account() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "KYC_MINT"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_cannot_transfer_to_unapproved_account() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.set_kyc_level(&owner, &alice, &KycLevel::Full);
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "KYC_TR"));
+
+    client.transfer(&alice, &bob, &10_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_kyc_balance_limit() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+    client.set_kyc_level(&owner, &alice, &KycLevel::Unverified);
+
+    // Default unverified balance cap is 3,500 BOBT
+    client.admin_mint(&owner, &alice, &3500_0000000, &String::from_str(&env, "KYC_BAL1"));
+    client.admin_mint(&owner, &alice, &1, &String::from_str(&env, "KYC_BAL2"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_kyc_transfer_limit() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.set_kyc_level(&owner, &alice, &KycLevel::Unverified);
+    client.set_kyc_level(&owner, &bob, &KycLevel::Full);
+    client.admin_mint(&owner, &alice, &3000_0000000, &String::from_str(&env, "KYC_CAP"));
+
+    // Default unverified per-transfer cap is 1,000 BOBT
+    client.transfer(&alice, &bob, &1000_0000000);
+    client.transfer(&alice, &bob, &1000_0000001);
+}
+
+#[test]
+fn test_set_tier_limits() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.set_kyc_level(&owner, &alice, &KycLevel::Basic);
+    client.set_kyc_level(&owner, &bob, &KycLevel::Basic);
+
+    let limits = TierLimits {
+        max_balance: 200_0000000,
+        max_transfer: 50_0000000,
+    };
+    client.set_tier_limits(&owner, &KycLevel::Basic, &limits);
+    assert_eq!(client.tier_limits(&KycLevel::Basic).max_balance, 200_0000000);
+
+    client.admin_mint(&owner, &alice, &200_0000000, &String::from_str(&env, "KYC_TIER"));
+    client.transfer(&alice, &bob, &50_0000000);
+    assert_eq!(client.balance(&bob), 50_0000000);
+}
+
+#[test]
+fn test_whitelist_disabled_ignores_kyc() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+
+    // No KYC level, but whitelist mode is off
+    client.admin_mint(&owner, &alice, &5000_0000000, &String::from_str(&env, "KYC_OFF"));
+    assert_eq!(client.balance(&alice), 5000_0000000);
+}
+
+// =============================================================================
+// LEGACY MINT / MIGRATION TESTS
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_legacy_mint_disabled() {
+    let (env, _owner, client) = setup();
+    let attacker = Address::generate(&env);
+
+    // Even with every auth mocked, the legacy entrypoint refuses to mint
+    client.mint(&attacker, &1000_0000000, &String::from_str(&env, "LEGACY"));
+}
+
+#[test]
+fn test_legacy_mint_leaves_state_untouched() {
+    let (env, _owner, client) = setup();
+    let attacker = Address::generate(&env);
+    let request_id = String::from_str(&env, "LEGACY_STATE");
+
+    assert!(client.try_mint(&attacker, &1000_0000000, &request_id).is_err());
+    assert_eq!(client.balance(&attacker), 0);
+    assert_eq!(client.total_supply(), 0);
+    assert!(!client.mint_request_exists(&request_id));
+}
+
+#[test]
+fn test_admin_mint_requires_minter_signature() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+
+    // Drop the mocked auths: the minter has not signed this invocation
+    env.set_auths(&[]);
+    let request_id = String::from_str(&env, "NO_SIG");
+    assert!(client
+        .try_admin_mint(&owner, &recipient, &1000_0000000, &request_id)
+        .is_err());
+    assert_eq!(client.total_supply(), 0);
+}
+
+/// Every role, for rewinding their member sets
+const ALL_ROLES: [Symbol; 10] = [
+    storage::ROLE_OWNER,
+    storage::ROLE_MINTER,
+    storage::ROLE_MASTER_MINTER,
+    storage::ROLE_PAUSER,
+    storage::ROLE_BLACKLISTER,
+    storage::ROLE_RESCUER,
+    storage::ROLE_KYC_OFFICER,
+    storage::ROLE_COMPLIANCE,
+    storage::ROLE_HOLD_OPERATOR,
+    storage::ROLE_ATTESTOR,
+];
+
+/// Rewind stored state to what a v1 deployment wrote: no role member sets
+fn rewind_to_v1(env: &Env, client: &BOBTTokenClient) {
+    env.as_contract(&client.address, || {
+        crate::storage::set_version(env, 1);
+        for role in ALL_ROLES.iter() {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RoleMembers(role.clone()));
+        }
+    });
+}
+
+/// Write a role grant the way v1 stored it: a bare flag with no member set
+fn grant_v1_role(env: &Env, client: &BOBTTokenClient, role: Symbol, account: &Address) {
+    env.as_contract(&client.address, || {
+        let key = DataKey::Role(role, account.clone());
+        env.storage().persistent().set(&key, &true);
+    });
+}
+
+/// Assert the `migrated` events of a run from `from` to `CONTRACT_VERSION`
+fn assert_migrated_events(env: &Env, client: &BOBTTokenClient, from: u32) {
+    let mut expected = vec![env];
+    for version in from..CONTRACT_VERSION {
+        expected.push_back((
+            client.address.clone(),
+            (Symbol::new(env, "migrated"),).into_val(env),
+            (version, version + 1).into_val(env),
+        ));
+    }
+    assert_eq!(env.events().all(), expected);
+}
+
+#[test]
+fn test_migrate_from_v1() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "V1_MINT");
+    client.admin_mint(&owner, &recipient, &1000_0000000, &request_id);
+
+    rewind_to_v1(&env, &client);
+    assert_eq!(client.version(), 1);
+    assert_eq!(client.role_member_count(&symbol_short!("owner")), 0);
+
+    client.migrate();
+    assert_migrated_events(&env, &client, 1);
+
+    // Owner is enumerable again; balances and request records are untouched
+    assert_eq!(client.version(), CONTRACT_VERSION);
+    assert_eq!(
+        client.get_role_members(&symbol_short!("owner")),
+        vec![&env, owner.clone()]
+    );
+    assert_eq!(client.balance(&recipient), 1000_0000000);
+    assert_eq!(client.get_mint_request(&request_id).amount, 1000_0000000);
+}
+
+#[test]
+fn test_backfill_v1_role_grants() {
+    let (env, owner, client) = setup();
+    let minter = Address::generate(&env);
+    let pauser = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    rewind_to_v1(&env, &client);
+    grant_v1_role(&env, &client, ROLE_MINTER, &minter);
+    grant_v1_role(&env, &client, ROLE_PAUSER, &pauser);
+    grant_v1_role(&env, &client, ROLE_PAUSER, &minter);
+
+    assert_eq!(client.role_member_count(&symbol_short!("minter")), 0);
+    assert!(client.has_role(&symbol_short!("minter"), &minter));
+
+    client.migrate();
+    let candidates = vec![&env, owner.clone(), minter.clone(), pauser.clone(), stranger.clone()];
+    assert_eq!(
+        client.backfill_role_members(&owner, &symbol_short!("minter"), &candidates),
+        1
+    );
+    assert_eq!(
+        client.backfill_role_members(&owner, &symbol_short!("pauser"), &candidates),
+        2
+    );
+    assert_eq!(
+        client.backfill_role_members(&owner, &symbol_short!("pauser"), &candidates),
+        0
+    );
+    assert_eq!(
+        client.try_backfill_role_members(&owner, &symbol_short!("nope"), &candidates),
+        Err(Ok(BOBTError::InvalidRole))
+    );
+
+    assert_eq!(
+        client.get_role_members(&symbol_short!("minter")),
+        vec![&env, minter.clone()]
+    );
+    assert_eq!(
+        client.get_role_members(&symbol_short!("pauser")),
+        vec![&env, minter.clone(), pauser.clone()]
+    );
+    assert_eq!(client.role_member_count(&symbol_short!("blklst")), 0);
+    assert!(!client.has_role(&symbol_short!("minter"), &stranger));
+}
+
+#[test]
+fn test_backfill_converts_legacy_freeze_flags() {
+    let (env, owner, client) = setup();
+    let flagged = Address::generate(&env);
+    let recorded = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let case_ref = String::from_str(&env, "CASE-9");
+    client.freeze(&owner, &recorded, &FreezeReason::Fraud, &case_ref, &None);
+    rewind_to_v1(&env, &client);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Frozen(flagged.clone()), &true);
+    });
+    assert_eq!(client.frozen_accounts(&0, &10), vec![&env, recorded.clone()]);
+
+    client.migrate();
+    let candidates = vec![&env, flagged.clone(), recorded.clone(), owner.clone()];
+    assert_eq!(client.backfill_freezes(&owner, &candidates), 1);
+    assert_eq!(client.backfill_freezes(&owner, &candidates), 0);
+
+    let record = client.freeze_record(&flagged).unwrap();
+    assert_eq!(record.reason, FreezeReason::Legacy);
+    assert_eq!(record.case_ref, String::from_str(&env, "legacy"));
+    assert_eq!(record.blacklister, owner);
+    assert_eq!(record.expiration_ledger, None);
+    assert_eq!(client.freeze_record(&recorded).unwrap().reason, FreezeReason::Fraud);
+    assert_eq!(client.frozen_list_len(), 2);
+    assert_eq!(
+        client.frozen_accounts(&0, &10),
+        vec![&env, recorded.clone(), flagged.clone()]
+    );
+    assert!(client.is_frozen(&flagged));
+    assert!(!client.is_frozen(&owner));
+
+    client.unfreeze(&owner, &flagged);
+    assert!(!client.is_frozen(&flagged));
+}
+
+#[test]
+fn test_backfill_indexes_v1_requests() {
+    let (env, owner, client) = setup();
+    let holder = Address::generate(&env);
+    let mint_id = String::from_str(&env, "V1-MINT");
+    let burn_id = String::from_str(&env, "V1-BURN");
+    let unknown_id = String::from_str(&env, "NEVER-USED");
+    rewind_to_v1(&env, &client);
+
+    // v1 stored bare request records, without history indexes or the used ID set
+    env.as_contract(&client.address, || {
+        let mint = MintRequest {
+            to: holder.clone(),
+            amount: 100_0000000,
+            ledger: 0,
+            timestamp: 0,
+            minter: owner.clone(),
+            external_ref: mint_id.clone(),
+        };
+        let burn = BurnRequest {
+            from: holder.clone(),
+            amount: 10_0000000,
+            ledger: 0,
+            timestamp: 0,
+            operator: owner.clone(),
+            external_ref: burn_id.clone(),
+        };
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::MintReq(mint_id.clone()), &mint);
+        storage.set(&DataKey::BurnReq(burn_id.clone()), &burn);
+    });
+
+    client.migrate();
+    assert_eq!(client.mint_request_count(), 0);
+    assert_eq!(client.request_count_for(&holder), 0);
+    let request_ids = vec![&env, mint_id.clone(), burn_id.clone(), unknown_id.clone()];
+    assert_eq!(client.backfill_requests(&owner, &request_ids), 2);
+
+    // Repeating or resuming a batch adds nothing twice
+    assert_eq!(client.backfill_requests(&owner, &request_ids), 0);
+
+    assert_eq!(client.mint_request_count(), 1);
+    assert_eq!(client.burn_request_count(), 1);
+    assert_eq!(client.list_mint_requests(&0, &10).get_unchecked(0).external_ref, mint_id);
+    assert_eq!(client.list_burn_requests(&0, &10).get_unchecked(0).external_ref, burn_id);
+    let expected = vec![
+        &env,
+        RequestRef {
+            kind: RequestKind::Mint,
+            request_id: mint_id.clone(),
+            sequence: 0,
+        },
+        RequestRef {
+            kind: RequestKind::Burn,
+            request_id: burn_id.clone(),
+            sequence: 0,
+        },
+    ];
+    assert_eq!(client.list_requests_for(&holder, &0, &10), expected);
+    assert_eq!(client.list_requests_for(&owner, &0, &10), expected);
+
+    // The IDs stay used once their records lapse out of storage
+    env.as_contract(&client.address, || {
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::MintReq(mint_id.clone()));
+        storage.remove(&DataKey::BurnReq(burn_id.clone()));
+    });
+    assert!(client.mint_request_exists(&mint_id));
+    assert!(client.burn_request_exists(&burn_id));
+    assert!(!client.burn_request_exists(&mint_id));
+    assert!(!client.mint_request_exists(&burn_id));
+    assert!(!client.mint_request_exists(&unknown_id));
+}
+
+#[test]
+fn test_backfills_reject_oversized_batch() {
+    let (env, owner, client) = setup();
+    let mut request_ids = Vec::new(&env);
+    for _ in 0..=MAX_REQUEST_BACKFILL_BATCH {
+        request_ids.push_back(String::from_str(&env, "BNB-DEP-000"));
+    }
+    let mut accounts = Vec::new(&env);
+    for _ in 0..=MAX_BACKFILL_BATCH {
+        accounts.push_back(Address::generate(&env));
+    }
+
+    assert_eq!(
+        client.try_backfill_requests(&owner, &request_ids),
+        Err(Ok(BOBTError::InvalidBatchSize))
+    );
+    assert_eq!(
+        client.try_backfill_role_members(&owner, &symbol_short!("minter"), &accounts),
+        Err(Ok(BOBTError::InvalidBatchSize))
+    );
+    assert_eq!(
+        client.try_backfill_freezes(&owner, &accounts),
+        Err(Ok(BOBTError::InvalidBatchSize))
+    );
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_backfill_requests(&stranger, &vec![&env]),
+        Err(Ok(BOBTError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_backfill_freezes(&stranger, &vec![&env]),
+        Err(Ok(BOBTError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_migrate_from_v2_skips_earlier_steps() {
+    let (env, _owner, client) = setup();
+    env.as_contract(&client.address, || crate::storage::set_version(&env, 2));
+
+    client.migrate();
+    assert_migrated_events(&env, &client, 2);
+    assert_eq!(client.version(), CONTRACT_VERSION);
+}
+
+#[test]
+fn test_migrate_twice_rejected() {
+    let (env, _owner, client) = setup();
+    rewind_to_v1(&env, &client);
+
+    client.migrate();
+    assert_eq!(
+        client.try_migrate(),
+        Err(Ok(BOBTError::AlreadyMigrated))
+    );
+    assert_eq!(client.version(), CONTRACT_VERSION);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #71)")]
+fn test_migrate_from_unknown_version_fails() {
+    let (env, _owner, client) = setup();
+    env.as_contract(&client.address, || crate::storage::set_version(&env, 99));
+
+    client.migrate();
+}
+
+#[test]
+fn test_migrate_requires_owner_auth() {
+    let (env, _owner, client) = setup();
+    env.as_contract(&client.address, || crate::storage::set_version(&env, 1));
+
+    env.set_auths(&[]);
+    assert!(client.try_migrate().is_err());
+    assert_eq!(client.version(), 1);
+}
+
+// =============================================================================
+// MINTER ALLOWANCE TESTS
+// =============================================================================
+
+/// Setup with a master minter (the owner) and a separate minter without allowance
+fn setup_with_master_minter() -> (Env, Address, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup();
+    let minter = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("mstrmint"), &owner, &None);
+    client.grant_role(&owner, &symbol_short!("minter"), &minter, &None);
+    (env, owner, minter, client)
+}
+
+#[test]
+fn test_configure_minter_and_mint() {
+    let (env, owner, minter, client) = setup_with_master_minter();
+    let recipient = Address::generate(&env);
+
+    client.configure_minter(&owner, &minter, &1000_0000000);
+    assert_eq!(client.minter_allowance(&minter), 1000_0000000);
+
+    client.admin_mint(&minter, &recipient, &300_0000000, &String::from_str(&env, "ALW1"));
+    assert_eq!(client.minter_allowance(&minter), 700_0000000);
+    assert_eq!(client.balance(&recipient), 300_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_minter_without_allowance_cannot_mint() {
+    let (env, _owner, minter, client) = setup_with_master_minter();
+    let recipient = Address::generate(&env);
+
+    client.admin_mint(&minter, &recipient, &1, &String::from_str(&env, "ALW_NONE"));
+}
+
+#[test]
+fn test_upgraded_minter_needs_configure_minter() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("mstrmint"), &owner, &None);
+    let minter = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    rewind_to_v1(&env, &client);
+    grant_v1_role(&env, &client, ROLE_MINTER, &minter);
+    client.migrate();
+    client.backfill_role_members(&owner, &symbol_short!("minter"), &vec![&env, minter.clone()]);
+
+    // Minters from before allowances start at zero
+    assert_eq!(client.minter_allowance(&minter), 0);
+    let request_id = String::from_str(&env, "POST_UPGRADE");
+    assert_eq!(
+        client.try_admin_mint(&minter, &recipient, &1_0000000, &request_id),
+        Err(Ok(BOBTError::MinterAllowanceExceeded))
+    );
+
+    client.configure_minter(&owner, &minter, &1000_0000000);
+    client.admin_mint(&minter, &recipient, &1_0000000, &request_id);
+    assert_eq!(client.balance(&recipient), 1_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_mint_exceeds_allowance() {
+    let (env, owner, minter, client) = setup_with_master_minter();
+    let recipient = Address::generate(&env);
+    client.configure_minter(&owner, &minter, &500_0000000);
+
+    client.admin_mint(&minter, &recipient, &400_0000000, &String::from_str(&env, "ALW_A"));
+    client.admin_mint(&minter, &recipient, &100_0000001, &String::from_str(&env, "ALW_B"));
+}
+
+#[test]
+fn test_increase_decrease_minter_allowance() {
+    let (_env, owner, minter, client) = setup_with_master_minter();
+
+    client.increase_minter_allowance(&owner, &minter, &500_0000000);
+    client.increase_minter_allowance(&owner, &minter, &250_0000000);
+    assert_eq!(client.minter_allowance(&minter), 750_0000000);
+
+    client.decrease_minter_allowance(&owner, &minter, &700_0000000);
+    assert_eq!(client.minter_allowance(&minter), 50_0000000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+fn test_decrease_below_zero() {
+    let (_env, owner, minter, client) = setup_with_master_minter();
+    client.configure_minter(&owner, &minter, &10_0000000);
+
+    client.decrease_minter_allowance(&owner, &minter, &10_0000001);
+}
+
+#[test]
+fn test_remove_minter() {
+    let (env, owner, minter, client) = setup_with_master_minter();
+    let recipient = Address::generate(&env);
+    client.configure_minter(&owner, &minter, &1000_0000000);
+
+    client.remove_minter(&owner, &minter);
+
+    assert_eq!(client.minter_allowance(&minter), 0);
+    let request_id = String::from_str(&env, "ALW_REMOVED");
+    assert!(client
+        .try_admin_mint(&minter, &recipient, &1_0000000, &request_id)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_unauthorized_configure_minter() {
+    let (env, _owner, minter, client) = setup_with_master_minter();
+    let attacker = Address::generate(&env);
+
+    // Holding the minter role is not enough to set allowances
+    client.configure_minter(&minter, &minter, &MAX_SUPPLY);
+    client.configure_minter(&attacker, &minter, &MAX_SUPPLY);
+}
+
+#[test]
+fn test_event_configure_minter() {
+    let (env, owner, minter, client) = setup_with_master_minter();
+
+    client.configure_minter(&owner, &minter, &1000_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "minter_allowance"), minter.clone()).into_val(&env),
+                (1000_0000000_i128, owner.clone()).into_val(&env),
+            ),
+        ]
+    );
+}
+
+// =============================================================================
+// OWNERSHIP TRANSFER TESTS
+// =============================================================================
+
+#[test]
+fn test_propose_ownership_keeps_current_owner() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+    let minter = Address::generate(&env);
+
+    client.propose_ownership(&owner, &new_owner, &Some(500));
+
+    let pending = client.pending_owner().unwrap();
+    assert_eq!(pending.new_owner, new_owner);
+    assert_eq!(pending.expiration_ledger, Some(500));
+
+    // Current owner keeps full control until acceptance
+    client.grant_role(&owner, &symbol_short!("minter"), &minter, &None);
+    assert!(client.has_role(&symbol_short!("minter"), &minter));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_only_proposed_owner_can_accept() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    client.propose_ownership(&owner, &new_owner, &None);
+    client.accept_ownership(&attacker);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_accept_without_proposal() {
+    let (env, _owner, client) = setup();
+    let new_owner = Address::generate(&env);
+
+    client.accept_ownership(&new_owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #92)")]
+fn test_accept_expired_offer() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+
+    client.propose_ownership(&owner, &new_owner, &Some(100));
+    env.ledger().set_sequence_number(101);
+
+    client.accept_ownership(&new_owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #91)")]
+fn test_propose_ownership_with_past_expiration() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+    env.ledger().set_sequence_number(200);
+
+    client.propose_ownership(&owner, &new_owner, &Some(100));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_cancel_ownership_transfer() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+
+    client.propose_ownership(&owner, &new_owner, &None);
+    client.cancel_ownership_transfer(&owner);
+    assert!(client.pending_owner().is_none());
+
+    client.accept_ownership(&new_owner);
+}
+
+#[test]
+fn test_renounce_ownership() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+    client.propose_ownership(&owner, &new_owner, &None);
+
+    client.renounce_ownership(&owner);
+
+    assert!(!client.has_role(&symbol_short!("owner"), &owner));
+    assert!(client.pending_owner().is_none());
+    assert!(client.try_owner().is_err());
+    assert!(client
+        .try_grant_role(&owner, &symbol_short!("minter"), &owner, &None)
+        .is_err());
+    assert!(client.try_accept_ownership(&new_owner).is_err());
+}
+
+#[test]
+fn test_event_accept_ownership() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+    client.propose_ownership(&owner, &new_owner, &None);
+
+    client.accept_ownership(&new_owner);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (
+                    Symbol::new(&env, "ownership_transferred"),
+                    owner.clone(),
+                    new_owner.clone()
+                )
+                    .into_val(&env),
+                ().into_val(&env),
+            ),
+        ]
+    );
+}
+
+// =============================================================================
+// ROLE ENUMERATION / ROLE ADMIN TESTS
+// =============================================================================
+
+#[test]
+fn test_role_members_enumeration() {
+    let (env, owner, client) = setup();
+    let minter1 = Address::generate(&env);
+    let minter2 = Address::generate(&env);
+
+    client.grant_role(&owner, &symbol_short!("minter"), &minter1, &None);
+    client.grant_role(&owner, &symbol_short!("minter"), &minter2, &None);
+    // Granting twice does not duplicate membership
+    client.grant_role(&owner, &symbol_short!("minter"), &minter1, &None);
+
+    assert_eq!(
+        client.get_role_members(&symbol_short!("minter")),
+        vec![&env, minter1.clone(), minter2.clone()]
+    );
+    assert_eq!(client.role_member_count(&symbol_short!("minter")), 2);
+
+    client.revoke_role(&owner, &symbol_short!("minter"), &minter1);
+    assert_eq!(
+        client.get_role_members(&symbol_short!("minter")),
+        vec![&env, minter2.clone()]
+    );
+    assert_eq!(client.role_member_count(&symbol_short!("blklst")), 0);
+}
+
+#[test]
+fn test_owner_listed_as_role_member() {
+    let (env, owner, client) = setup();
+    let new_owner = Address::generate(&env);
+
+    assert_eq!(
+        client.get_role_members(&symbol_short!("owner")),
+        vec![&env, owner.clone()]
+    );
+
+    client.propose_ownership(&owner, &new_owner, &None);
+    client.accept_ownership(&new_owner);
+    assert_eq!(
+        client.get_role_members(&symbol_short!("owner")),
+        vec![&env, new_owner.clone()]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_role_members_invalid_role() {
+    let (_env, _owner, client) = setup();
+
+    client.get_role_members(&symbol_short!("bogus"));
+}
+
+#[test]
+fn test_compliance_lead_manages_blacklisters() {
+    let (env, owner, client) = setup();
+    let compliance = Symbol::new(&env, "compliance");
+    let lead = Address::generate(&env);
+    let blacklister = Address::generate(&env);
+
+    assert_eq!(client.get_role_admin(&symbol_short!("blklst")), symbol_short!("owner"));
+
+    client.grant_role(&owner, &compliance, &lead, &None);
+    client.set_role_admin(&owner, &symbol_short!("blklst"), &compliance);
+    assert_eq!(client.get_role_admin(&symbol_short!("blklst")), compliance);
+
+    // The compliance lead can now manage blacklisters without owner keys
+    client.grant_role(&lead, &symbol_short!("blklst"), &blacklister, &None);
+    assert!(client.has_role(&symbol_short!("blklst"), &blacklister));
+    client.revoke_role(&lead, &symbol_short!("blklst"), &blacklister);
+    assert!(!client.has_role(&symbol_short!("blklst"), &blacklister));
+
+    // The owner keeps control as well
+    client.grant_role(&owner, &symbol_short!("blklst"), &blacklister, &None);
+    assert!(client.has_role(&symbol_short!("blklst"), &blacklister));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_role_admin_limited_to_its_role() {
+    let (env, owner, client) = setup();
+    let compliance = Symbol::new(&env, "compliance");
+    let lead = Address::generate(&env);
+    client.grant_role(&owner, &compliance, &lead, &None);
+    client.set_role_admin(&owner, &symbol_short!("blklst"), &compliance);
+
+    // Managing blacklisters does not extend to minters
+    client.grant_role(&lead, &symbol_short!("minter"), &lead, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_unauthorized_set_role_admin() {
+    let (env, _owner, client) = setup();
+    let attacker = Address::generate(&env);
+
+    client.set_role_admin(&attacker, &symbol_short!("minter"), &symbol_short!("pauser"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_cannot_delegate_owner_role() {
+    let (env, owner, client) = setup();
+
+    client.set_role_admin(&owner, &symbol_short!("owner"), &Symbol::new(&env, "compliance"));
+}
+
+// =============================================================================
+// TIME-BOUND ROLE TESTS
+// =============================================================================
+
+#[test]
+fn test_role_grant_expires() {
+    let (env, owner, client) = setup();
+    let pauser = Address::generate(&env);
+
+    client.grant_role(&owner, &symbol_short!("pauser"), &pauser, &Some(1000));
+    assert_eq!(client.role_expiration(&symbol_short!("pauser"), &pauser), Some(1000));
+
+    // Still valid on the expiration ledger itself
+    env.ledger().set_sequence_number(1000);
+    assert!(client.has_role(&symbol_short!("pauser"), &pauser));
+    client.pause(&pauser);
+    client.unpause(&pauser);
+
+    env.ledger().set_sequence_number(1001);
+    assert!(!client.has_role(&symbol_short!("pauser"), &pauser));
+    assert_eq!(client.role_member_count(&symbol_short!("pauser")), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_expired_minter_cannot_mint() {
+    let (env, owner, client) = setup_with_minter();
+    let minter = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("minter"), &minter, &Some(500));
+    client.configure_minter(&owner, &minter, &1000_0000000);
+
+    client.admin_mint(&minter, &recipient, &10_0000000, &String::from_str(&env, "EXP1"));
+
+    env.ledger().set_sequence_number(501);
+    client.admin_mint(&minter, &recipient, &10_0000000, &String::from_str(&env, "EXP2"));
+}
+
+#[test]
+fn test_regrant_replaces_expiration() {
+    let (env, owner, client) = setup();
+    let rescuer = Address::generate(&env);
+
+    client.grant_role(&owner, &symbol_short!("rescuer"), &rescuer, &Some(100));
+    client.grant_role(&owner, &symbol_short!("rescuer"), &rescuer, &None);
+    assert_eq!(client.role_expiration(&symbol_short!("rescuer"), &rescuer), None);
+
+    env.ledger().set_sequence_number(10_000);
+    assert!(client.has_role(&symbol_short!("rescuer"), &rescuer));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #91)")]
+fn test_cannot_grant_already_expired_role() {
+    let (env, owner, client) = setup();
+    let pauser = Address::generate(&env);
+    env.ledger().set_sequence_number(200);
+
+    client.grant_role(&owner, &symbol_short!("pauser"), &pauser, &Some(199));
+}
+
+// =============================================================================
+// UPGRADE TIMELOCK TESTS
+// =============================================================================
+
+#[test]
+fn test_schedule_upgrade_sets_eta() {
+    let (env, owner, client) = setup();
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    env.ledger().set_sequence_number(100);
+
+    let eta = client.schedule_upgrade(&owner, &wasm_hash);
+    assert_eq!(eta, 100 + DEFAULT_UPGRADE_DELAY);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "upgrade_scheduled"), owner.clone()).into_val(&env),
+                (wasm_hash.clone(), eta).into_val(&env),
+            ),
+        ]
+    );
+
+    let pending = client.pending_upgrade().unwrap();
+    assert_eq!(pending.wasm_hash, wasm_hash);
+    assert_eq!(pending.scheduled_ledger, 100);
+    assert_eq!(pending.eta_ledger, eta);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #74)")]
+fn test_execute_upgrade_before_delay_fails() {
+    let (env, owner, client) = setup();
+    client.schedule_upgrade(&owner, &BytesN::from_array(&env, &[7; 32]));
+
+    env.ledger().set_sequence_number(DEFAULT_UPGRADE_DELAY - 1);
+    client.execute_upgrade(&owner);
+}
+
+#[test]
+fn test_execute_upgrade_after_delay_reaches_deployer() {
+    let (env, owner, client) = setup();
+    client.schedule_upgrade(&owner, &BytesN::from_array(&env, &[7; 32]));
+    env.ledger().set_sequence_number(DEFAULT_UPGRADE_DELAY);
+
+    // The timelock no longer blocks; the host rejects the unknown WASM instead
+    let result = client.try_execute_upgrade(&owner);
+    assert!(matches!(result, Err(Err(_))));
+    assert!(client.pending_upgrade().is_some());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+fn test_upgrade_without_schedule_fails() {
+    let (env, owner, client) = setup();
+    client.upgrade(&owner, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #72)")]
+fn test_upgrade_rejects_unscheduled_hash() {
+    let (env, owner, client) = setup();
+    client.schedule_upgrade(&owner, &BytesN::from_array(&env, &[7; 32]));
+    env.ledger().set_sequence_number(DEFAULT_UPGRADE_DELAY);
+
+    client.upgrade(&owner, &BytesN::from_array(&env, &[8; 32]));
+}
+
+#[test]
+fn test_cancel_upgrade() {
+    let (env, owner, client) = setup();
+    let wasm_hash = BytesN::from_array(&env, &[7; 32]);
+    client.schedule_upgrade(&owner, &wasm_hash);
+
+    assert_eq!(
+        client.try_schedule_upgrade(&owner, &wasm_hash),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+
+    client.cancel_upgrade(&owner);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "upgrade_cancelled"), owner.clone()).into_val(&env),
+                wasm_hash.into_val(&env),
+            ),
+        ]
+    );
+    assert!(client.pending_upgrade().is_none());
+    assert_eq!(
+        client.try_execute_upgrade(&owner),
+        Err(Ok(BOBTError::RequestNotFound))
+    );
+}
+
+#[test]
+fn test_set_upgrade_delay_bounds() {
+    let (env, owner, client) = setup();
+
+    assert_eq!(
+        client.try_set_upgrade_delay(&owner, &(MIN_UPGRADE_DELAY - 1)),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_upgrade_delay(&owner, &(MAX_UPGRADE_DELAY + 1)),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+
+    client.set_upgrade_delay(&owner, &MIN_UPGRADE_DELAY);
+    assert_eq!(client.upgrade_delay(), MIN_UPGRADE_DELAY);
+
+    let eta = client.schedule_upgrade(&owner, &BytesN::from_array(&env, &[7; 32]));
+    assert_eq!(eta, MIN_UPGRADE_DELAY);
+}
+
+#[test]
+fn test_schedule_upgrade_requires_owner() {
+    let (env, _owner, client) = setup();
+    let attacker = Address::generate(&env);
+
+    assert_eq!(
+        client.try_schedule_upgrade(&attacker, &BytesN::from_array(&env, &[7; 32])),
+        Err(Ok(BOBTError::Unauthorized))
+    );
+}
+
+// =============================================================================
+// CLAWBACK / SEIZURE TESTS
+// =============================================================================
+
+/// Setup with the owner as blacklister and a funded holder
+fn setup_with_holder() -> (Env, Address, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    let holder = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    client.admin_mint(&owner, &holder, &1000_0000000, &String::from_str(&env, "SEIZE_FUND"));
+    (env, owner, holder, client)
+}
+
+#[test]
+fn test_clawback_to_custody() {
+    let (env, owner, holder, client) = setup_with_holder();
+    let custody = Address::generate(&env);
+    let legal_ref = String::from_str(&env, "ORDER-2024-117");
+
+    // No prior freeze needed
+    client.clawback(&owner, &holder, &300_0000000, &Some(custody.clone()), &legal_ref);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), custody.clone()).into_val(&env),
+                300_0000000_i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "seizure"), holder.clone(), owner.clone()).into_val(&env),
+                (300_0000000_i128, legal_ref.clone(), Some(custody.clone())).into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance(&holder), 700_0000000);
+    assert_eq!(client.balance(&custody), 300_0000000);
+    assert_eq!(client.total_supply(), 1000_0000000);
+
+    let record = client.get_clawback(&legal_ref);
+    assert_eq!(record.account, holder);
+    assert_eq!(record.amount, 300_0000000);
+    assert_eq!(record.destination, Some(custody));
+    assert_eq!(record.blacklister, owner);
+}
+
+#[test]
+fn test_clawback_burn_from_frozen_account() {
+    let (env, owner, holder, client) = setup_with_holder();
+    freeze(&env, &client, &owner, &holder);
+
+    client.clawback(&owner, &holder, &250_0000000, &None, &String::from_str(&env, "ORDER-B"));
+
+    assert_eq!(client.balance(&holder), 750_0000000);
+    assert_eq!(client.total_supply(), 750_0000000);
+    assert!(client.is_frozen(&holder));
+}
+
+#[test]
+fn test_clawback_rejects_reused_legal_ref() {
+    let (env, owner, holder, client) = setup_with_holder();
+    let legal_ref = String::from_str(&env, "ORDER-DUP");
+    client.clawback(&owner, &holder, &10_0000000, &None, &legal_ref);
+
+    assert_eq!(
+        client.try_clawback(&owner, &holder, &10_0000000, &None, &legal_ref),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(client.balance(&holder), 990_0000000);
+}
+
+#[test]
+fn test_clawback_validation() {
+    let (env, owner, holder, client) = setup_with_holder();
+    let custody = Address::generate(&env);
+    let legal_ref = String::from_str(&env, "ORDER-BAD");
+
+    assert_eq!(
+        client.try_clawback(&owner, &holder, &1001_0000000, &None, &legal_ref),
+        Err(Ok(BOBTError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_clawback(&owner, &holder, &10_0000000, &Some(holder.clone()), &legal_ref),
+        Err(Ok(BOBTError::CannotTransferToSelf))
+    );
+
+    freeze(&env, &client, &owner, &custody);
+    assert_eq!(
+        client.try_clawback(&owner, &holder, &10_0000000, &Some(custody), &legal_ref),
+        Err(Ok(BOBTError::AccountFrozen))
+    );
+    assert!(client.try_get_clawback(&legal_ref).is_err());
+
+    let too_long = String::from_bytes(&env, &[b'R'; MAX_REQUEST_ID_LEN as usize + 1]);
+    for bad_ref in [String::from_str(&env, ""), too_long] {
+        assert_eq!(
+            client.try_clawback(&owner, &holder, &10_0000000, &None, &bad_ref),
+            Err(Ok(BOBTError::InvalidRequestId))
+        );
+    }
+}
+
+#[test]
+fn test_clawback_to_custody_requires_kyc() {
+    let (env, owner, client) = setup_permissioned();
+    let holder = Address::generate(&env);
+    let custody = Address::generate(&env);
+    let legal_ref = String::from_str(&env, "ORDER-KYC");
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    client.set_kyc_level(&owner, &holder, &KycLevel::Basic);
+    client.admin_mint(&owner, &holder, &100_0000000, &String::from_str(&env, "KYC_FUND"));
+
+    assert_eq!(
+        client.try_clawback(&owner, &holder, &10_0000000, &Some(custody.clone()), &legal_ref),
+        Err(Ok(BOBTError::AccountNotApproved))
+    );
+
+    client.set_kyc_level(&owner, &custody, &KycLevel::Full);
+    client.clawback(&owner, &holder, &10_0000000, &Some(custody.clone()), &legal_ref);
+    assert_eq!(client.balance(&custody), 10_0000000);
+}
+
+#[test]
+fn test_clawback_releases_holds_it_undercuts() {
+    let (env, owner, holder, client) = setup_with_holder();
+    client.grant_role(&owner, &symbol_short!("holdop"), &owner, &None);
+    let older = String::from_str(&env, "HOLD-OLD");
+    let newer = String::from_str(&env, "HOLD-NEW");
+    client.place_hold(&owner, &holder, &300_0000000, &older, &None);
+    client.place_hold(&owner, &holder, &400_0000000, &newer, &None);
+
+    // 500 left: the newest hold no longer fits and is released
+    client.clawback(&owner, &holder, &500_0000000, &None, &String::from_str(&env, "ORDER-H"));
+    assert_eq!(client.account_holds(&holder), vec![&env, older.clone()]);
+    assert_eq!(client.held_balance(&holder), 300_0000000);
+    assert!(client.try_get_hold(&newer).is_err());
+
+    // Destroying everything releases the rest
+    freeze(&env, &client, &owner, &holder);
+    client.destroy_frozen_funds(&owner, &holder);
+    assert_eq!(client.account_holds(&holder).len(), 0);
+    assert_eq!(client.held_balance(&holder), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_clawback_requires_blacklister() {
+    let (env, _owner, holder, client) = setup_with_holder();
+    let attacker = Address::generate(&env);
+
+    client.clawback(
+        &attacker,
+        &holder,
+        &10_0000000,
+        &Some(attacker.clone()),
+        &String::from_str(&env, "FAKE"),
+    );
+}
+
+#[test]
+fn test_reverse_mint_burns_from_recipient() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let alice = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-FRAUD-001");
+    let reason = String::from_str(&env, "Deposit reversed by bank");
+    client.admin_mint(&owner, &alice, &500_0000000, &request_id);
+
+    assert_eq!(client.reverse_mint(&owner, &request_id, &reason), 500_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "clawback"), owner.clone(), alice.clone()).into_val(&env),
+                500_0000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint_reversed"), alice.clone(), owner.clone()).into_val(&env),
+                (request_id.clone(), 500_0000000i128, 500_0000000i128, reason.clone())
+                    .into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(client.get_mint_reversal(&request_id).burned, 500_0000000);
+}
+
+#[test]
+fn test_reverse_mint_records_shortfall_once() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-FRAUD-002");
+    let reason = String::from_str(&env, "Stolen card");
+    client.admin_mint(&owner, &alice, &500_0000000, &request_id);
+    client.transfer(&alice, &bob, &300_0000000);
+    freeze(&env, &client, &owner, &alice);
+
+    assert_eq!(client.reverse_mint(&owner, &request_id, &reason), 200_0000000);
+
+    let reversal = client.get_mint_reversal(&request_id);
+    assert_eq!(reversal.minted, 500_0000000);
+    assert_eq!(reversal.burned, 200_0000000);
+    assert_eq!(reversal.reason, reason);
+    assert_eq!(client.balance(&bob), 300_0000000);
+    assert_eq!(client.total_supply(), 300_0000000);
+
+    assert_eq!(
+        client.try_reverse_mint(&owner, &request_id, &reason),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(
+        client.try_reverse_mint(&owner, &String::from_str(&env, "UNKNOWN"), &reason),
+        Err(Ok(BOBTError::RequestNotFound))
+    );
+    assert_eq!(
+        client.try_reverse_mint(&owner, &String::from_str(&env, ""), &reason),
+        Err(Ok(BOBTError::InvalidRequestId))
+    );
+}
+
+#[test]
+fn test_reverse_mint_after_thirty_days() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let alice = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-FRAUD-LATE");
+    let reason = String::from_str(&env, "Chargeback after settlement");
+    client.admin_mint(&owner, &alice, &500_0000000, &request_id);
+
+    let request_key = DataKey::MintReq(request_id.clone());
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    let request_ttl =
+        || env.as_contract(&client.address, || env.storage().persistent().get_ttl(&request_key));
+    assert_eq!(request_ttl(), max_ttl);
+
+    // Keep everything but the mint record alive across the 60 day gap
+    env.as_contract(&client.address, || {
+        env.storage().instance().extend_ttl(max_ttl, max_ttl);
+        for key in [
+            DataKey::Role(ROLE_BLACKLISTER, owner.clone()),
+            DataKey::Balance(alice.clone()),
+            DataKey::BalCkpt(alice.clone()),
+        ] {
+            env.storage().persistent().extend_ttl(&key, max_ttl, max_ttl);
+        }
+    });
+    env.ledger().with_mut(|li| li.sequence_number += 17280 * 60);
+    assert_eq!(request_ttl(), max_ttl - 17280 * 60);
+
+    assert_eq!(client.reverse_mint(&owner, &request_id, &reason), 500_0000000);
+    assert_eq!(client.balance(&alice), 0);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+fn test_reverse_mint_releases_holds_it_undercuts() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    client.grant_role(&owner, &symbol_short!("holdop"), &owner, &None);
+    let alice = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-FRAUD-003");
+    let reason = String::from_str(&env, "Deposit reversed by bank");
+    client.admin_mint(&owner, &alice, &500_0000000, &request_id);
+    client.admin_mint(&owner, &alice, &200_0000000, &String::from_str(&env, "BNB-OK-001"));
+    let older = String::from_str(&env, "HOLD-OLD");
+    let newer = String::from_str(&env, "HOLD-NEW");
+    client.place_hold(&owner, &alice, &150_0000000, &older, &None);
+    client.place_hold(&owner, &alice, &400_0000000, &newer, &None);
+
+    // 200 left: the newest hold no longer fits and is released
+    assert_eq!(client.reverse_mint(&owner, &request_id, &reason), 500_0000000);
+    assert_eq!(client.account_holds(&alice), vec![&env, older.clone()]);
+    assert_eq!(client.held_balance(&alice), 150_0000000);
+    assert!(client.try_get_hold(&newer).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_reverse_mint_requires_blacklister() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-FRAUD-003");
+    client.admin_mint(&owner, &alice, &500_0000000, &request_id);
+
+    client.reverse_mint(&owner, &request_id, &String::from_str(&env, "Fraud"));
+}
+
+// =============================================================================
+// FREEZE RECORD TESTS
+// =============================================================================
+
+#[test]
+fn test_freeze_records_details() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let account = Address::generate(&env);
+    let case_ref = String::from_str(&env, "UIF-2024-031");
+    env.ledger().set_sequence_number(50);
+
+    client.freeze(&owner, &account, &FreezeReason::CourtOrder, &case_ref, &Some(500));
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "freeze"), account.clone()).into_val(&env),
+                (owner.clone(), FreezeReason::CourtOrder, case_ref.clone(), Some(500u32))
+                    .into_val(&env),
+            ),
+        ]
+    );
+
+    let record = client.freeze_record(&account).unwrap();
+    assert_eq!(record.reason, FreezeReason::CourtOrder);
+    assert_eq!(record.case_ref, case_ref);
+    assert_eq!(record.blacklister, owner);
+    assert_eq!(record.ledger, 50);
+    assert_eq!(record.expiration_ledger, Some(500));
+}
+
+#[test]
+fn test_freeze_lapses_after_expiration() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "FRZ_EXP"));
+    let case_ref = String::from_str(&env, "DISPUTE-7");
+
+    client.freeze(&owner, &alice, &FreezeReason::Investigation, &case_ref, &Some(100));
+    env.ledger().set_sequence_number(100);
+    assert!(client.is_frozen(&alice));
+    assert!(client.try_transfer(&alice, &bob, &10_0000000).is_err());
+
+    env.ledger().set_sequence_number(101);
+    assert!(!client.is_frozen(&alice));
+    client.transfer(&alice, &bob, &10_0000000);
+    assert_eq!(client.frozen_accounts(&0, &10).len(), 0);
+
+    // The lapsed entry stays listed until cleared or replaced
+    assert_eq!(client.frozen_list_len(), 1);
+    client.freeze(&owner, &alice, &FreezeReason::Fraud, &case_ref, &None);
+    assert_eq!(client.frozen_list_len(), 1);
+    client.unfreeze(&owner, &alice);
+    assert_eq!(client.frozen_list_len(), 0);
+}
+
+#[test]
+fn test_frozen_accounts_enumeration() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    freeze(&env, &client, &owner, &a);
+    freeze(&env, &client, &owner, &b);
+    freeze(&env, &client, &owner, &c);
+    assert_eq!(client.frozen_accounts(&0, &10), vec![&env, a.clone(), b.clone(), c.clone()]);
+
+    client.unfreeze(&owner, &a);
+    assert_eq!(client.frozen_list_len(), 2);
+    assert_eq!(client.frozen_accounts(&0, &10), vec![&env, c.clone(), b.clone()]);
+    assert_eq!(client.frozen_accounts(&1, &1), vec![&env, b]);
+    assert_eq!(client.frozen_accounts(&5, &10).len(), 0);
+}
+
+#[test]
+fn test_listing_frozen_accounts_extends_freeze_entries() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let account = Address::generate(&env);
+    freeze(&env, &client, &owner, &account);
+
+    let keys = [
+        DataKey::FrozenIdx(0),
+        DataKey::FrozenPos(account.clone()),
+        DataKey::FreezeRec(account.clone()),
+    ];
+    let ttls = || {
+        env.as_contract(&client.address, || {
+            keys.clone().map(|key| env.storage().persistent().get_ttl(&key))
+        })
+    };
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    assert_eq!(ttls(), [max_ttl; 3]);
+
+    // Entries age while idle; listing them restores the maximum TTL
+    env.ledger().with_mut(|li| li.sequence_number += 100_000);
+    assert_eq!(ttls(), [max_ttl - 100_000; 3]);
+    assert_eq!(client.frozen_accounts(&0, &10), vec![&env, account.clone()]);
+    assert_eq!(ttls(), [max_ttl; 3]);
+}
+
+#[test]
+fn test_legacy_freeze_flag_honored() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let account = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::Frozen(account.clone()), &true);
+    });
+
+    assert!(client.is_frozen(&account));
+    assert!(client.freeze_record(&account).is_none());
+
+    // Re-freezing attaches a record and lists the account
+    let case_ref = String::from_str(&env, "LEGACY-1");
+    client.freeze(&owner, &account, &FreezeReason::Sanctions, &case_ref, &None);
+    assert_eq!(client.frozen_accounts(&0, &10), vec![&env, account.clone()]);
+
+    client.unfreeze(&owner, &account);
+    assert!(!client.is_frozen(&account));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #91)")]
+fn test_freeze_rejects_past_expiration() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let account = Address::generate(&env);
+    env.ledger().set_sequence_number(300);
+
+    let case_ref = String::from_str(&env, "X");
+    client.freeze(&owner, &account, &FreezeReason::Other, &case_ref, &Some(299));
+}
+
+// =============================================================================
+// BALANCE HOLD TESTS
+// =============================================================================
+
+/// Setup with the owner as hold operator and a holder owning 1,000 BOBT
+fn setup_with_hold_operator() -> (Env, Address, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    let holder = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("holdop"), &owner, &None);
+    client.admin_mint(&owner, &holder, &1000_0000000, &String::from_str(&env, "HOLD_FUND"));
+    (env, owner, holder, client)
+}
+
+#[test]
+fn test_hold_limits_spendable_balance() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let bob = Address::generate(&env);
+    let hold_id = String::from_str(&env, "OFFRAMP-1");
+
+    client.place_hold(&owner, &holder, &600_0000000, &hold_id, &None);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "hold_placed"), holder.clone(), owner.clone()).into_val(&env),
+                (hold_id.clone(), 600_0000000_i128, None::<u32>).into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance(&holder), 1000_0000000);
+    assert_eq!(client.held_balance(&holder), 600_0000000);
+    assert_eq!(client.spendable_balance(&holder), 400_0000000);
+
+    assert_eq!(
+        client.try_transfer(&holder, &bob, &400_0000001),
+        Err(Ok(BOBTError::FundsOnHold))
+    );
+    assert_eq!(client.try_burn(&holder, &500_0000000), Err(Ok(BOBTError::FundsOnHold)));
+    client.transfer(&holder, &bob, &400_0000000);
+    assert_eq!(client.spendable_balance(&holder), 0);
+}
+
+#[test]
+fn test_release_hold() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let bob = Address::generate(&env);
+    let hold_id = String::from_str(&env, "DISPUTE-9");
+    client.place_hold(&owner, &holder, &1000_0000000, &hold_id, &None);
+
+    client.release_hold(&owner, &hold_id);
+    assert_eq!(client.held_balance(&holder), 0);
+    assert_eq!(client.account_holds(&holder).len(), 0);
+    assert!(client.try_get_hold(&hold_id).is_err());
+
+    client.transfer(&holder, &bob, &1000_0000000);
+    assert_eq!(client.balance(&bob), 1000_0000000);
+}
+
+#[test]
+fn test_execute_hold_pays_out_held_funds() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let bank = Address::generate(&env);
+    let hold_id = String::from_str(&env, "BANK-TX-42");
+    client.place_hold(&owner, &holder, &250_0000000, &hold_id, &None);
+
+    client.execute_hold(&owner, &hold_id, &bank);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), bank.clone()).into_val(&env),
+                250_0000000_i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "hold_executed"), holder.clone(), owner.clone()).into_val(&env),
+                (hold_id.clone(), bank.clone(), 250_0000000_i128).into_val(&env),
+            ),
+        ]
+    );
+
+    assert_eq!(client.balance(&holder), 750_0000000);
+    assert_eq!(client.balance(&bank), 250_0000000);
+    assert_eq!(client.held_balance(&holder), 0);
+    assert_eq!(
+        client.try_execute_hold(&owner, &hold_id, &bank),
+        Err(Ok(BOBTError::RequestNotFound))
+    );
+}
+
+#[test]
+fn test_hold_expires() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let bank = Address::generate(&env);
+    let hold_id = String::from_str(&env, "SHORT");
+    client.place_hold(&owner, &holder, &1000_0000000, &hold_id, &Some(100));
+
+    env.ledger().set_sequence_number(101);
+    assert_eq!(client.spendable_balance(&holder), 1000_0000000);
+    assert_eq!(
+        client.try_execute_hold(&owner, &hold_id, &bank),
+        Err(Ok(BOBTError::Expired))
+    );
+
+    // Placing a new hold prunes the expired one
+    client.place_hold(&owner, &holder, &10_0000000, &String::from_str(&env, "NEXT"), &None);
+    assert_eq!(client.account_holds(&holder).len(), 1);
+}
+
+#[test]
+fn test_place_hold_validation() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let hold_id = String::from_str(&env, "H1");
+    client.place_hold(&owner, &holder, &700_0000000, &hold_id, &None);
+
+    assert_eq!(
+        client.try_place_hold(&owner, &holder, &1_0000000, &hold_id, &None),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    let other_id = String::from_str(&env, "H2");
+    assert_eq!(
+        client.try_place_hold(&owner, &holder, &300_0000001, &other_id, &None),
+        Err(Ok(BOBTError::FundsOnHold))
+    );
+    assert_eq!(
+        client.try_place_hold(&owner, &holder, &1001_0000000, &other_id, &None),
+        Err(Ok(BOBTError::InsufficientBalance))
+    );
+
+    env.ledger().set_sequence_number(10);
+    assert_eq!(
+        client.try_place_hold(&owner, &holder, &1_0000000, &other_id, &Some(9)),
+        Err(Ok(BOBTError::InvalidExpiration))
+    );
+
+    let too_long = String::from_bytes(&env, &[b'H'; MAX_REQUEST_ID_LEN as usize + 1]);
+    for bad_id in [String::from_str(&env, ""), too_long] {
+        assert_eq!(
+            client.try_place_hold(&owner, &holder, &1_0000000, &bad_id, &None),
+            Err(Ok(BOBTError::InvalidRequestId))
+        );
+    }
+}
+
+#[test]
+fn test_hold_count_is_capped() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let ids = [
+        "C00", "C01", "C02", "C03", "C04", "C05", "C06", "C07", "C08", "C09", "C10", "C11",
+        "C12", "C13", "C14", "C15", "C16", "C17", "C18", "C19",
+    ];
+    for id in ids {
+        client.place_hold(&owner, &holder, &1_0000000, &String::from_str(&env, id), &None);
+    }
+
+    assert_eq!(
+        client.try_place_hold(&owner, &holder, &1_0000000, &String::from_str(&env, "C20"), &None),
+        Err(Ok(BOBTError::TooManyHolds))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_place_hold_requires_hold_operator() {
+    let (env, _owner, holder, client) = setup_with_hold_operator();
+    let attacker = Address::generate(&env);
+
+    client.place_hold(&attacker, &holder, &1_0000000, &String::from_str(&env, "X"), &None);
+}
+
+// =============================================================================
+// PERMIT TESTS
+// =============================================================================
+
+/// Stellar account address controlled by an ed25519 key
+fn account_address(env: &Env, key: &SigningKey) -> Address {
+    // ScVal::Address(ScAddress::Account(PublicKey::Ed25519(key)))
+    let mut xdr = Bytes::from_array(env, &[0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0]);
+    xdr.extend_from_array(&key.verifying_key().to_bytes());
+    Address::from_xdr(env, &xdr).unwrap()
+}
+
+/// Sign a permit payload for `client` with `key`
+fn sign_permit(
+    env: &Env,
+    client: &BOBTTokenClient,
+    key: &SigningKey,
+    spender: &Address,
+    amount: i128,
+    nonce: u64,
+) -> BytesN<64> {
+    let owner = account_address(env, key);
+    let digest = env.as_contract(&client.address, || {
+        signature::permit_digest(env, &owner, spender, amount, 1000, nonce)
+    });
+    BytesN::from_array(env, &key.sign(&digest.to_array()).to_bytes())
+}
+
+#[test]
+fn test_permit_sets_allowance_without_owner_auth() {
+    let (env, _owner, client) = setup();
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let holder = account_address(&env, &key);
+    let spender = Address::generate(&env);
+    let signature = sign_permit(&env, &client, &key, &spender, 500_0000000, 0);
+
+    env.set_auths(&[]);
+    client.permit(&holder, &spender, &500_0000000, &1000, &0, &signature);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("approve"), holder.clone(), spender.clone()).into_val(&env),
+                (500_0000000i128, 1000u32).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.allowance(&holder, &spender), 500_0000000);
+    assert_eq!(client.nonce(&holder), 1);
+
+    // The nonce is kept for the maximum TTL so old permits stay consumed
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    let ttl = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Nonce(holder.clone()))
+    });
+    assert_eq!(ttl, max_ttl);
+}
+
+#[test]
+fn test_permit_rejects_replayed_nonce() {
+    let (env, _owner, client) = setup();
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let holder = account_address(&env, &key);
+    let spender = Address::generate(&env);
+    let signature = sign_permit(&env, &client, &key, &spender, 500_0000000, 0);
+
+    client.permit(&holder, &spender, &500_0000000, &1000, &0, &signature);
+    client.approve(&holder, &spender, &0, &1000);
+
+    assert_eq!(
+        client.try_permit(&holder, &spender, &500_0000000, &1000, &0, &signature),
+        Err(Ok(BOBTError::InvalidNonce))
+    );
+    assert_eq!(client.allowance(&holder, &spender), 0);
+}
+
+#[test]
+fn test_permit_rejects_tampered_or_foreign_signature() {
+    let (env, _owner, client) = setup();
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let holder = account_address(&env, &key);
+    let spender = Address::generate(&env);
+    let signature = sign_permit(&env, &client, &key, &spender, 500_0000000, 0);
+
+    // A signature that does not verify traps in the host rather than
+    // returning InvalidSignature
+
+    // Different amount than signed
+    assert_eq!(
+        client.try_permit(&holder, &spender, &900_0000000, &1000, &0, &signature),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // Signed by another key
+    let other_key = SigningKey::from_bytes(&[8; 32]);
+    let forged = sign_permit(&env, &client, &other_key, &spender, 500_0000000, 0);
+    assert_eq!(
+        client.try_permit(&holder, &spender, &500_0000000, &1000, &0, &forged),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // Signed for another deployment of the token
+    let other_id = env.register(BOBTToken, ());
+    let other_client = BOBTTokenClient::new(&env, &other_id);
+    let foreign = sign_permit(&env, &other_client, &key, &spender, 500_0000000, 0);
+    assert_eq!(
+        client.try_permit(&holder, &spender, &500_0000000, &1000, &0, &foreign),
+        Err(Err(InvokeError::Abort))
+    );
+
+    assert_eq!(client.allowance(&holder, &spender), 0);
+    assert_eq!(client.nonce(&holder), 0);
+}
+
+#[test]
+fn test_permit_rejects_contract_owner() {
+    let (env, _owner, client) = setup();
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let contract_owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let signature = sign_permit(&env, &client, &key, &spender, 500_0000000, 0);
+
+    assert_eq!(
+        client.try_permit(&contract_owner, &spender, &500_0000000, &1000, &0, &signature),
+        Err(Ok(BOBTError::InvalidSignature))
+    );
+}
+
+#[test]
+fn test_permit_blocked_by_approve_pause_and_freeze() {
+    let (env, owner, client) = setup();
+    client.grant_role(&owner, &symbol_short!("pauser"), &owner, &None);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let holder = account_address(&env, &key);
+    let spender = Address::generate(&env);
+    let signature = sign_permit(&env, &client, &key, &spender, 500_0000000, 0);
+
+    client.pause_operations(&owner, &PAUSE_APPROVE);
+    assert_eq!(
+        client.try_permit(&holder, &spender, &500_0000000, &1000, &0, &signature),
+        Err(Ok(BOBTError::ContractPaused))
+    );
+    client.unpause_operations(&owner, &PAUSE_APPROVE);
+
+    freeze(&env, &client, &owner, &holder);
+    assert_eq!(
+        client.try_permit(&holder, &spender, &500_0000000, &1000, &0, &signature),
+        Err(Ok(BOBTError::AccountFrozen))
+    );
+}
+
+// =============================================================================
+// RELAYED TRANSFER TESTS
+// =============================================================================
+
+/// Setup with a key-controlled holder funded with 1000 BOBT
+fn setup_with_signer() -> (Env, SigningKey, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    let key = SigningKey::from_bytes(&[7; 32]);
+    let holder = account_address(&env, &key);
+    client.admin_mint(&owner, &holder, &1000_0000000, &String::from_str(&env, "REQ-SIGNER"));
+    (env, key, holder, client)
+}
+
+/// Sign a transfer authorization valid until ledger 1000 for `client`
+#[allow(clippy::too_many_arguments)]
+fn sign_transfer_auth(
+    env: &Env,
+    client: &BOBTTokenClient,
+    key: &SigningKey,
+    to: &Address,
+    amount: i128,
+    fee: i128,
+    relayer: &Address,
+    nonce: &BytesN<32>,
+) -> BytesN<64> {
+    let from = account_address(env, key);
+    let digest = env.as_contract(&client.address, || {
+        signature::transfer_authorization_digest(
+            env, &from, to, amount, fee, relayer, 1000, nonce,
+        )
+    });
+    BytesN::from_array(env, &key.sign(&digest.to_array()).to_bytes())
+}
+
+#[test]
+fn test_transfer_with_authorization_pays_recipient_and_relayer() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 100_0000000, 5000000, &relayer, &nonce);
+
+    env.set_auths(&[]);
+    client.transfer_with_authorization(
+        &holder, &merchant, &100_0000000, &5000000, &relayer, &1000, &nonce, &signature,
+    );
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), merchant.clone()).into_val(&env),
+                100_0000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), relayer.clone()).into_val(&env),
+                5000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "auth_used"), holder.clone(), relayer.clone()).into_val(&env),
+                (nonce.clone(), 5000000i128).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&holder), 899_5000000);
+    assert_eq!(client.balance(&merchant), 100_0000000);
+    assert_eq!(client.balance(&relayer), 5000000);
+    assert!(client.authorization_state(&holder, &nonce));
+}
+
+#[test]
+fn test_transfer_with_authorization_rejects_replay() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 100_0000000, 0, &relayer, &nonce);
+
+    client.transfer_with_authorization(
+        &holder, &merchant, &100_0000000, &0, &relayer, &1000, &nonce, &signature,
+    );
+
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &0, &relayer, &1000, &nonce, &signature,
+        ),
+        Err(Ok(BOBTError::InvalidNonce))
+    );
+    assert_eq!(client.balance(&merchant), 100_0000000);
+}
+
+#[test]
+fn test_transfer_with_authorization_rejects_expired_and_tampered() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 100_0000000, 5000000, &relayer, &nonce);
+
+    // Relayer raising its own fee: the signature no longer verifies and traps
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &9000000, &relayer, &1000, &nonce, &signature,
+        ),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // Another relayer claiming the fee
+    let other_relayer = Address::generate(&env);
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &5000000, &other_relayer, &1000, &nonce,
+            &signature,
+        ),
+        Err(Err(InvokeError::Abort))
+    );
+
+    env.ledger().set_sequence_number(1001);
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &5000000, &relayer, &1000, &nonce, &signature,
+        ),
+        Err(Ok(BOBTError::Expired))
+    );
+
+    assert_eq!(client.balance(&holder), 1000_0000000);
+    assert!(!client.authorization_state(&holder, &nonce));
+}
+
+#[test]
+fn test_transfer_with_authorization_fee_must_be_covered() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 1000_0000000, 5000000, &relayer, &nonce);
+
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &1000_0000000, &5000000, &relayer, &1000, &nonce, &signature,
+        ),
+        Err(Ok(BOBTError::InsufficientBalance))
+    );
+
+    // Nothing moved and the authorization can still be used later
+    assert_eq!(client.balance(&holder), 1000_0000000);
+    assert_eq!(client.balance(&merchant), 0);
+    assert!(!client.authorization_state(&holder, &nonce));
+}
+
+#[test]
+fn test_cancel_authorization() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 100_0000000, 0, &relayer, &nonce);
+    let digest = env.as_contract(&client.address, || {
+        signature::cancel_authorization_digest(&env, &holder, &nonce)
+    });
+    let cancel_signature = BytesN::from_array(&env, &key.sign(&digest.to_array()).to_bytes());
+
+    env.set_auths(&[]);
+    client.cancel_authorization(&holder, &nonce, &cancel_signature);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "auth_canceled"), holder.clone()).into_val(&env),
+                nonce.clone().into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &0, &relayer, &1000, &nonce, &signature,
+        ),
+        Err(Ok(BOBTError::InvalidNonce))
+    );
+    assert_eq!(
+        client.try_cancel_authorization(&holder, &nonce, &cancel_signature),
+        Err(Ok(BOBTError::InvalidNonce))
+    );
+
+    // The canceled nonce is kept for the maximum TTL
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    let ttl = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::AuthNonce(holder.clone(), nonce.clone()))
+    });
+    assert_eq!(ttl, max_ttl);
+}
+
+#[test]
+fn test_cancel_authorization_rejects_bad_signature() {
+    let (env, key, holder, client) = setup_with_signer();
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let other_nonce = BytesN::from_array(&env, &[2; 32]);
+    let digest = env.as_contract(&client.address, || {
+        signature::cancel_authorization_digest(&env, &holder, &other_nonce)
+    });
+    let signature = BytesN::from_array(&env, &key.sign(&digest.to_array()).to_bytes());
+
+    assert_eq!(
+        client.try_cancel_authorization(&holder, &nonce, &signature),
+        Err(Err(InvokeError::Abort))
+    );
+    assert!(!client.authorization_state(&holder, &nonce));
+}
+
+#[test]
+fn test_used_authorization_outlives_its_validity() {
+    let (env, key, holder, client) = setup_with_signer();
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+    let current = env.ledger().sequence();
+
+    // Authorizations valid beyond the maximum TTL cannot be marked used for long enough
+    let too_late = current + max_ttl + 1;
+    let from = holder.clone();
+    let digest = env.as_contract(&client.address, || {
+        signature::transfer_authorization_digest(
+            &env, &from, &merchant, 100_0000000, 0, &relayer, too_late, &nonce,
+        )
+    });
+    let signature = BytesN::from_array(&env, &key.sign(&digest.to_array()).to_bytes());
+    assert_eq!(
+        client.try_transfer_with_authorization(
+            &holder, &merchant, &100_0000000, &0, &relayer, &too_late, &nonce, &signature,
+        ),
+        Err(Ok(BOBTError::InvalidExpiration))
+    );
+
+    let valid_until = current + max_ttl - 10;
+    let digest = env.as_contract(&client.address, || {
+        signature::transfer_authorization_digest(
+            &env, &from, &merchant, 100_0000000, 0, &relayer, valid_until, &nonce,
+        )
+    });
+    let signature = BytesN::from_array(&env, &key.sign(&digest.to_array()).to_bytes());
+    client.transfer_with_authorization(
+        &holder, &merchant, &100_0000000, &0, &relayer, &valid_until, &nonce, &signature,
+    );
+    let live_until = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::AuthNonce(holder.clone(), nonce.clone()))
+    }) + current;
+    assert!(live_until >= valid_until, "{live_until} < {valid_until}");
+}
+
+// =============================================================================
+// TRANSFER FEE TESTS
+// =============================================================================
+
+/// Setup with a funded holder and a 0.5% fee between 0.1 and 5 BOBT
+fn setup_with_fee() -> (Env, Address, Address, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    let holder = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.admin_mint(&owner, &holder, &100000_0000000, &String::from_str(&env, "REQ-FEE"));
+    let config = FeeConfig {
+        rate_bps: 50,
+        min_fee: 1000000,
+        max_fee: 5_0000000,
+        collector: collector.clone(),
+    };
+    client.set_fee_config(&owner, &Some(config));
+    (env, owner, holder, collector, client)
+}
+
+#[test]
+fn test_transfer_fee_is_deducted_and_collected() {
+    let (env, _owner, holder, collector, client) = setup_with_fee();
+    let merchant = Address::generate(&env);
+
+    client.transfer(&holder, &merchant, &100_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), merchant.clone()).into_val(&env),
+                99_5000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), holder.clone(), collector.clone()).into_val(&env),
+                5000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "fee_charged"), holder.clone(), merchant.clone())
+                    .into_val(&env),
+                (5000000i128, collector.clone()).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&holder), 99900_0000000);
+    assert_eq!(client.balance(&merchant), 99_5000000);
+    assert_eq!(client.balance(&collector), 5000000);
+    assert_eq!(client.total_supply(), 100000_0000000);
+}
+
+#[test]
+fn test_quote_transfer_fee_applies_min_and_max() {
+    let (env, _owner, holder, _collector, client) = setup_with_fee();
+    let merchant = Address::generate(&env);
+
+    assert_eq!(client.quote_transfer_fee(&holder, &merchant, &100_0000000), 5000000);
+    assert_eq!(client.quote_transfer_fee(&holder, &merchant, &1_0000000), 1000000);
+    assert_eq!(client.quote_transfer_fee(&holder, &merchant, &10000_0000000), 5_0000000);
+
+    client.transfer(&holder, &merchant, &10000_0000000);
+    assert_eq!(client.balance(&merchant), 9995_0000000);
+}
+
+#[test]
+fn test_transfer_fee_exemptions() {
+    let (env, owner, holder, collector, client) = setup_with_fee();
+    let merchant = Address::generate(&env);
+
+    client.set_fee_exempt(&owner, &merchant, &true);
+    assert!(client.is_fee_exempt(&merchant));
+    assert_eq!(client.quote_transfer_fee(&holder, &merchant, &100_0000000), 0);
+    assert_eq!(client.quote_transfer_fee(&merchant, &holder, &100_0000000), 0);
+    assert_eq!(client.quote_transfer_fee(&holder, &collector, &100_0000000), 0);
+
+    client.transfer(&holder, &merchant, &100_0000000);
+    assert_eq!(client.balance(&merchant), 100_0000000);
+    assert_eq!(client.balance(&collector), 0);
+
+    client.set_fee_exempt(&owner, &merchant, &false);
+    assert_eq!(client.quote_transfer_fee(&holder, &merchant, &100_0000000), 5000000);
+}
+
+#[test]
+fn test_transfer_fee_must_leave_net_amount() {
+    let (env, _owner, holder, _collector, client) = setup_with_fee();
+    let merchant = Address::generate(&env);
+
+    assert_eq!(
+        client.try_transfer(&holder, &merchant, &1000000),
+        Err(Ok(BOBTError::BelowMinTransfer))
+    );
+}
+
+#[test]
+fn test_set_fee_config_validation_and_disable() {
+    let (env, owner, holder, collector, client) = setup_with_fee();
+    let merchant = Address::generate(&env);
+    let too_high = FeeConfig {
+        rate_bps: 1001,
+        min_fee: 0,
+        max_fee: 5_0000000,
+        collector: collector.clone(),
+    };
+    let inverted = FeeConfig {
+        rate_bps: 50,
+        min_fee: 5_0000000,
+        max_fee: 1000000,
+        collector: collector.clone(),
+    };
+
+    assert_eq!(
+        client.try_set_fee_config(&owner, &Some(too_high)),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+    assert_eq!(
+        client.try_set_fee_config(&owner, &Some(inverted)),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+
+    client.set_fee_config(&owner, &None);
+    assert_eq!(client.fee_config(), None);
+
+    client.transfer(&holder, &merchant, &100_0000000);
+    assert_eq!(client.balance(&merchant), 100_0000000);
+}
+
+#[test]
+fn test_relayer_fee_is_not_charged_transfer_fee() {
+    let (env, key, holder, client) = setup_with_signer();
+    let owner = client.owner();
+    let collector = Address::generate(&env);
+    let config = FeeConfig {
+        rate_bps: 50,
+        min_fee: 1000000,
+        max_fee: 5_0000000,
+        collector: collector.clone(),
+    };
+    client.set_fee_config(&owner, &Some(config));
+    let merchant = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    let nonce = BytesN::from_array(&env, &[1; 32]);
+    let signature =
+        sign_transfer_auth(&env, &client, &key, &merchant, 100_0000000, 5000000, &relayer, &nonce);
+
+    client.transfer_with_authorization(
+        &holder, &merchant, &100_0000000, &5000000, &relayer, &1000, &nonce, &signature,
+    );
+
+    assert_eq!(client.balance(&merchant), 99_5000000);
+    assert_eq!(client.balance(&relayer), 5000000);
+    assert_eq!(client.balance(&collector), 5000000);
+    assert_eq!(client.balance(&holder), 899_5000000);
+}
+
+#[test]
+fn test_hold_payout_is_not_charged_transfer_fee() {
+    let (env, owner, holder, client) = setup_with_hold_operator();
+    let collector = Address::generate(&env);
+    let config = FeeConfig {
+        rate_bps: 50,
+        min_fee: 1000000,
+        max_fee: 5_0000000,
+        collector: collector.clone(),
+    };
+    client.set_fee_config(&owner, &Some(config));
+    let merchant = Address::generate(&env);
+    let hold_id = String::from_str(&env, "ESCROW-1");
+
+    client.place_hold(&owner, &holder, &100_0000000, &hold_id, &None);
+    client.execute_hold(&owner, &hold_id, &merchant);
+
+    assert_eq!(client.balance(&merchant), 100_0000000);
+    assert_eq!(client.balance(&collector), 0);
+    assert_eq!(client.balance(&holder), 900_0000000);
+}
+
+#[test]
+fn test_fee_collector_must_be_able_to_receive() {
+    let (env, owner, holder, collector, client) = setup_with_fee();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let merchant = Address::generate(&env);
+    let config = client.fee_config().unwrap();
+
+    freeze(&env, &client, &owner, &collector);
+    assert_eq!(
+        client.try_transfer(&holder, &merchant, &100_0000000),
+        Err(Ok(BOBTError::AccountFrozen))
+    );
+    assert_eq!(
+        client.try_set_fee_config(&owner, &Some(config.clone())),
+        Err(Ok(BOBTError::AccountFrozen))
+    );
+    client.unfreeze(&owner, &collector);
+
+    // Permissioned mode: the collector needs KYC like any recipient
+    client.grant_role(&owner, &symbol_short!("kyc"), &owner, &None);
+    client.set_kyc_level(&owner, &holder, &KycLevel::Full);
+    client.set_kyc_level(&owner, &merchant, &KycLevel::Full);
+    let mut token_config = client.get_config();
+    token_config.require_whitelist = true;
+    client.set_config(&owner, &token_config);
+
+    assert_eq!(
+        client.try_transfer(&holder, &merchant, &100_0000000),
+        Err(Ok(BOBTError::AccountNotApproved))
+    );
+    assert_eq!(
+        client.try_set_fee_config(&owner, &Some(config.clone())),
+        Err(Ok(BOBTError::AccountNotApproved))
+    );
+
+    client.set_kyc_level(&owner, &collector, &KycLevel::Full);
+    client.set_fee_config(&owner, &Some(config));
+    client.transfer(&holder, &merchant, &100_0000000);
+    assert_eq!(client.balance(&collector), 5000000);
+}
+
+// =============================================================================
+// BATCH TRANSFER TESTS
+// =============================================================================
+
+#[test]
+fn test_batch_transfer_pays_each_recipient() {
+    let (env, owner, client) = setup_with_minter();
+    let payer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.admin_mint(&owner, &payer, &1000_0000000, &String::from_str(&env, "REQ-PAYROLL"));
+
+    let recipients = vec![&env, (alice.clone(), 300_0000000i128), (bob.clone(), 200_0000000i128)];
+    client.batch_transfer(&payer, &recipients);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), payer.clone(), alice.clone()).into_val(&env),
+                300_0000000i128.into_val(&env)
+            ),
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), payer.clone(), bob.clone()).into_val(&env),
+                200_0000000i128.into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&payer), 500_0000000);
+    assert_eq!(client.balance(&alice), 300_0000000);
+    assert_eq!(client.balance(&bob), 200_0000000);
+}
+
+#[test]
+fn test_batch_transfer_is_all_or_nothing() {
+    let (env, owner, client) = setup_with_minter();
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    let payer = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.admin_mint(&owner, &payer, &1000_0000000, &String::from_str(&env, "REQ-PAYROLL"));
+
+    let over_budget =
+        vec![&env, (alice.clone(), 600_0000000i128), (bob.clone(), 600_0000000i128)];
+    assert_eq!(
+        client.try_batch_transfer(&payer, &over_budget),
+        Err(Ok(BOBTError::InsufficientBalance))
+    );
+
+    let zero_leg = vec![&env, (alice.clone(), 100_0000000i128), (bob.clone(), 0i128)];
+    assert_eq!(
+        client.try_batch_transfer(&payer, &zero_leg),
+        Err(Ok(BOBTError::NegativeAmount))
+    );
+
+    freeze(&env, &client, &owner, &bob);
+    let frozen_leg = vec![&env, (alice.clone(), 100_0000000i128), (bob.clone(), 100_0000000i128)];
+    assert_eq!(
+        client.try_batch_transfer(&payer, &frozen_leg),
+        Err(Ok(BOBTError::AccountFrozen))
+    );
+
+    assert_eq!(client.balance(&payer), 1000_0000000);
+    assert_eq!(client.balance(&alice), 0);
+}
+
+#[test]
+fn test_batch_transfer_size_limits() {
+    let (env, owner, client) = setup_with_minter();
+    let payer = Address::generate(&env);
+    client.admin_mint(&owner, &payer, &1000_0000000, &String::from_str(&env, "REQ-PAYROLL"));
+
+    let empty: Vec<(Address, i128)> = vec![&env];
+    assert_eq!(
+        client.try_batch_transfer(&payer, &empty),
+        Err(Ok(BOBTError::InvalidBatchSize))
+    );
+
+    let mut oversized = vec![&env];
+    for _ in 0..=MAX_BATCH_SIZE {
+        oversized.push_back((Address::generate(&env), 1_0000000i128));
+    }
+    assert_eq!(
+        client.try_batch_transfer(&payer, &oversized),
+        Err(Ok(BOBTError::InvalidBatchSize))
+    );
+}
+
+/// Soroban per-transaction resource limits on mainnet
+const TX_MAX_INSTRUCTIONS: i64 = 100_000_000;
+const TX_MAX_MEMORY_BYTES: i64 = 40 * 1024 * 1024;
+const TX_MAX_READ_ENTRIES: u32 = 100;
+const TX_MAX_WRITE_ENTRIES: u32 = 50;
+const TX_MAX_EVENTS_SIZE_BYTES: u32 = 16 * 1024;
+
+#[test]
+fn test_batch_transfer_max_size_fits_network_limits() {
+    // Worst case: permissioned mode and a transfer fee on every leg
+    let (env, owner, client) = setup_permissioned();
+    let payer = Address::generate(&env);
+    let collector = Address::generate(&env);
+    client.set_kyc_level(&owner, &payer, &KycLevel::Full);
+    client.set_kyc_level(&owner, &collector, &KycLevel::Full);
+    client.admin_mint(&owner, &payer, &10000_0000000, &String::from_str(&env, "REQ-PAYROLL"));
+    let config = FeeConfig {
+        rate_bps: 50,
+        min_fee: 1000000,
+        max_fee: 5_0000000,
+        collector: collector.clone(),
+    };
+    client.set_fee_config(&owner, &Some(config));
+
+    let mut recipients = vec![&env];
+    for _ in 0..MAX_BATCH_SIZE {
+        let employee = Address::generate(&env);
+        client.set_kyc_level(&owner, &employee, &KycLevel::Full);
+        recipients.push_back((employee, 100_0000000i128));
+    }
+
+    client.batch_transfer(&payer, &recipients);
+
+    // Resources of the last invocation, so read them before any other call.
+    // The native test contract skips VM costs and the contract code read, so
+    // leave headroom on CPU and one read entry. Ledger entries are the binding
+    // limits: about four reads and two writes per leg.
+    let resources = env.cost_estimate().resources();
+    assert!(resources.instructions * 4 < TX_MAX_INSTRUCTIONS, "{:?}", resources);
+    assert!(resources.mem_bytes * 4 < TX_MAX_MEMORY_BYTES, "{:?}", resources);
+    assert!(resources.read_entries < TX_MAX_READ_ENTRIES, "{:?}", resources);
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES, "{:?}", resources);
+    assert!(resources.contract_events_size_bytes <= TX_MAX_EVENTS_SIZE_BYTES, "{:?}", resources);
+
+    assert_eq!(client.balance(&payer), 8000_0000000);
+}
+
+#[test]
+fn test_admin_mint_writes_do_not_grow_with_supply_history() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    for i in 0..MAX_SUPPLY_CHECKPOINTS {
+        env.ledger().set_sequence_number(100 + i);
+        let request_id = String::from_bytes(&env, &[b'A' + (i / 26) as u8, b'A' + (i % 26) as u8]);
+        client.admin_mint(&owner, &alice, &1_0000000, &request_id);
+    }
+
+    env.ledger().set_sequence_number(100 + MAX_SUPPLY_CHECKPOINTS);
+    client.admin_mint(&owner, &alice, &1_0000000, &String::from_str(&env, "REQ-LAST"));
+
+    // One supply slot is written however long the history is; the rest is
+    // the instance, the request ID shard and the recipient's balance entries
+    let resources = env.cost_estimate().resources();
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES, "{:?}", resources);
+    assert!(resources.write_bytes < 6 * 1024, "{:?}", resources);
+    assert!(resources.read_bytes < 6 * 1024, "{:?}", resources);
+    assert_eq!(client.total_supply(), i128::from(MAX_SUPPLY_CHECKPOINTS + 1) * 1_0000000);
+}
+
+#[test]
+fn test_backfill_requests_max_batch_fits_network_limits() {
+    // Worst case: a mint and a burn under every ID, each between two new accounts
+    let (env, owner, client) = setup();
+    let mut request_ids = vec![&env];
+    env.as_contract(&client.address, || {
+        for i in 0..MAX_REQUEST_BACKFILL_BATCH {
+            let request_id = String::from_bytes(&env, &[b'A' + i as u8; 8]);
+            let mint = MintRequest {
+                to: Address::generate(&env),
+                amount: 100_0000000,
+                ledger: 0,
+                timestamp: 0,
+                minter: Address::generate(&env),
+                external_ref: request_id.clone(),
+            };
+            let burn = BurnRequest {
+                from: Address::generate(&env),
+                amount: 10_0000000,
+                ledger: 0,
+                timestamp: 0,
+                operator: Address::generate(&env),
+                external_ref: request_id.clone(),
+            };
+            let storage = env.storage().persistent();
+            storage.set(&DataKey::MintReq(request_id.clone()), &mint);
+            storage.set(&DataKey::BurnReq(request_id.clone()), &burn);
+            request_ids.push_back(request_id);
+        }
+    });
+
+    assert_eq!(
+        client.backfill_requests(&owner, &request_ids),
+        2 * MAX_REQUEST_BACKFILL_BATCH
+    );
+
+    let resources = env.cost_estimate().resources();
+    assert!(resources.instructions * 4 < TX_MAX_INSTRUCTIONS, "{:?}", resources);
+    assert!(resources.read_entries < TX_MAX_READ_ENTRIES, "{:?}", resources);
+    assert!(resources.write_entries <= TX_MAX_WRITE_ENTRIES, "{:?}", resources);
+}
+
+// =============================================================================
+// PAYMENT MEMO TESTS
+// =============================================================================
+
+#[test]
+fn test_transfer_with_memo_publishes_memo() {
+    let (env, owner, client) = setup_with_minter();
+    let customer = Address::generate(&env);
+    let merchant = Address::generate(&env);
+    client.admin_mint(&owner, &customer, &1000_0000000, &String::from_str(&env, "REQ-POS"));
+    let memo = Bytes::from_slice(&env, b"QR-INV-2024-000123");
+
+    client.transfer_with_memo(&customer, &merchant, &25_0000000, &memo);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (symbol_short!("transfer"), customer.clone(), merchant.clone()).into_val(&env),
+                (25_0000000i128, memo.clone()).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&merchant), 25_0000000);
+}
+
+#[test]
+fn test_transfer_with_memo_length_is_bounded() {
+    let (env, owner, client) = setup_with_minter();
+    let customer = Address::generate(&env);
+    let merchant = Address::generate(&env);
+    client.admin_mint(&owner, &customer, &1000_0000000, &String::from_str(&env, "REQ-POS"));
+
+    let longest = Bytes::from_array(&env, &[b'A'; MAX_MEMO_LEN as usize]);
+    client.transfer_with_memo(&customer, &merchant, &1_0000000, &longest);
+
+    let too_long = Bytes::from_array(&env, &[b'A'; MAX_MEMO_LEN as usize + 1]);
+    assert_eq!(
+        client.try_transfer_with_memo(&customer, &merchant, &1_0000000, &too_long),
+        Err(Ok(BOBTError::MemoTooLong))
+    );
+    assert_eq!(client.balance(&merchant), 1_0000000);
+}
+
+// =============================================================================
+// TWO-PHASE MINT TESTS
+// =============================================================================
+
+#[test]
+fn test_reserve_then_confirm_mint() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-DEP-001");
+
+    client.reserve_mint(&owner, &request_id, &recipient, &500_0000000, &100);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint_reserved"), recipient.clone(), owner.clone())
+                    .into_val(&env),
+                (500_0000000i128, request_id.clone(), 100u32).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.balance(&recipient), 0);
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(client.reserved_supply(), 500_0000000);
+    assert_eq!(client.minter_allowance(&owner), MAX_SUPPLY - 500_0000000);
+    assert_eq!(client.get_mint_reservation(&request_id).confirm_by_ledger, 100);
+
+    client.confirm_mint(&owner, &request_id);
+
+    assert_eq!(client.balance(&recipient), 500_0000000);
+    assert_eq!(client.total_supply(), 500_0000000);
+    assert_eq!(client.reserved_supply(), 0);
+    assert_eq!(client.get_mint_request(&request_id).amount, 500_0000000);
+    assert!(client.try_get_mint_reservation(&request_id).is_err());
+    assert_eq!(
+        client.try_confirm_mint(&owner, &request_id),
+        Err(Ok(BOBTError::RequestNotFound))
+    );
+}
+
+#[test]
+fn test_void_mint_restores_allowance() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-DEP-002");
+    client.reserve_mint(&owner, &request_id, &recipient, &500_0000000, &100);
+
+    client.void_mint(&owner, &request_id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint_voided"), recipient.clone(), owner.clone())
+                    .into_val(&env),
+                (500_0000000i128, request_id.clone()).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(client.reserved_supply(), 0);
+    assert_eq!(client.minter_allowance(&owner), MAX_SUPPLY);
+    assert_eq!(client.total_supply(), 0);
+    assert!(client.try_get_mint_request(&request_id).is_err());
+
+    // The bank reference was consumed by the reservation
+    assert!(client.mint_request_exists(&request_id));
+    assert_eq!(
+        client.try_reserve_mint(&owner, &request_id, &recipient, &500_0000000, &100),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(
+        client.try_admin_mint(&owner, &recipient, &500_0000000, &request_id),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+}
+
+#[test]
+fn test_confirm_mint_after_deadline_fails() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-DEP-003");
+    client.reserve_mint(&owner, &request_id, &recipient, &500_0000000, &100);
+
+    env.ledger().set_sequence_number(101);
+    assert_eq!(
+        client.try_confirm_mint(&owner, &request_id),
+        Err(Ok(BOBTError::Expired))
+    );
+
+    // Lapsed reservations can still be voided
+    client.void_mint(&owner, &request_id);
+    assert_eq!(client.reserved_supply(), 0);
+
+    let late_id = String::from_str(&env, "BNB-DEP-003B");
+    assert_eq!(
+        client.try_reserve_mint(&owner, &late_id, &recipient, &500_0000000, &100),
+        Err(Ok(BOBTError::InvalidExpiration))
+    );
+}
+
+#[test]
+fn test_reservation_outlives_its_confirm_deadline() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-DEP-006");
+    let max_ttl = env.as_contract(&client.address, || env.storage().max_ttl());
+
+    assert_eq!(
+        client.try_reserve_mint(&owner, &request_id, &recipient, &500_0000000, &(max_ttl + 1)),
+        Err(Ok(BOBTError::InvalidExpiration))
+    );
+
+    let confirm_by = max_ttl - 10;
+    client.reserve_mint(&owner, &request_id, &recipient, &500_0000000, &confirm_by);
+    let live_until = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::MintRsv(request_id.clone()))
+    });
+    assert!(live_until >= confirm_by, "{live_until} < {confirm_by}");
+}
+
+#[test]
+fn test_only_reserving_or_master_minter_settles_reservation() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let other_minter = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("minter"), &other_minter, &None);
+    client.configure_minter(&owner, &other_minter, &1000_0000000);
+    let master = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("mstrmint"), &master, &None);
+
+    let confirmed = String::from_str(&env, "BNB-DEP-007");
+    let voided = String::from_str(&env, "BNB-DEP-008");
+    client.reserve_mint(&other_minter, &confirmed, &recipient, &100_0000000, &100);
+    client.reserve_mint(&other_minter, &voided, &recipient, &100_0000000, &100);
+
+    // Another minter without MASTER_MINTER cannot settle it
+    client.revoke_role(&owner, &symbol_short!("mstrmint"), &owner);
+    assert_eq!(
+        client.try_confirm_mint(&owner, &confirmed),
+        Err(Ok(BOBTError::Unauthorized))
+    );
+    assert_eq!(client.try_void_mint(&owner, &voided), Err(Ok(BOBTError::Unauthorized)));
+
+    client.confirm_mint(&other_minter, &confirmed);
+    client.void_mint(&master, &voided);
+    assert_eq!(client.balance(&recipient), 100_0000000);
+    assert_eq!(client.minter_allowance(&other_minter), 900_0000000);
+    assert_eq!(client.reserved_supply(), 0);
+}
+
+#[test]
+fn test_reserved_and_minted_request_ids_do_not_collide() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let minted = String::from_str(&env, "BNB-DEP-004");
+    let reserved = String::from_str(&env, "BNB-DEP-005");
+
+    client.admin_mint(&owner, &recipient, &100_0000000, &minted);
+    client.reserve_mint(&owner, &reserved, &recipient, &100_0000000, &100);
+
+    assert_eq!(
+        client.try_reserve_mint(&owner, &minted, &recipient, &100_0000000, &100),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(
+        client.try_reserve_mint(&owner, &reserved, &recipient, &100_0000000, &100),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+    assert_eq!(
+        client.try_admin_mint(&owner, &recipient, &100_0000000, &reserved),
+        Err(Ok(BOBTError::RequestAlreadyExists))
+    );
+}
+
+#[test]
+fn test_void_mint_refund_is_checked() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let other_minter = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("minter"), &other_minter, &None);
+    client.configure_minter(&owner, &other_minter, &1000_0000000);
+
+    // A refund past i128::MAX fails instead of wrapping
+    let overflowing = String::from_str(&env, "BNB-DEP-010");
+    client.reserve_mint(&other_minter, &overflowing, &recipient, &500_0000000, &100);
+    client.configure_minter(&owner, &other_minter, &i128::MAX);
+    assert_eq!(
+        client.try_void_mint(&owner, &overflowing),
+        Err(Ok(BOBTError::OverflowError))
+    );
+
+    // A minter removed since reserving gets nothing back
+    client.configure_minter(&owner, &other_minter, &1000_0000000);
+    let removed = String::from_str(&env, "BNB-DEP-011");
+    client.reserve_mint(&other_minter, &removed, &recipient, &300_0000000, &100);
+    client.revoke_role(&owner, &symbol_short!("minter"), &other_minter);
+    client.void_mint(&owner, &overflowing);
+    client.void_mint(&owner, &removed);
+    assert_eq!(client.minter_allowance(&other_minter), 700_0000000);
+    assert_eq!(client.reserved_supply(), 0);
+}
+
+#[test]
+fn test_reserve_mint_checks_kyc() {
+    let (env, owner, client) = setup_permissioned();
+    let alice = Address::generate(&env);
+    let request_id = String::from_str(&env, "BNB-DEP-012");
+
+    assert_eq!(
+        client.try_reserve_mint(&owner, &request_id, &alice, &100_0000000, &100),
+        Err(Ok(BOBTError::AccountNotApproved))
+    );
+
+    // Default unverified balance cap is 3,500 BOBT
+    client.set_kyc_level(&owner, &alice, &KycLevel::Unverified);
+    client.admin_mint(&owner, &alice, &3000_0000000, &String::from_str(&env, "KYC_RSV"));
+    assert_eq!(
+        client.try_reserve_mint(&owner, &request_id, &alice, &500_0000001, &100),
+        Err(Ok(BOBTError::KycLimitExceeded))
+    );
+    client.reserve_mint(&owner, &request_id, &alice, &500_0000000, &100);
+}
+
+#[test]
+fn test_reserved_supply_counts_against_max_supply() {
+    let (env, owner, client) = setup_with_minter();
+    let recipient = Address::generate(&env);
+    let mut config = client.get_config();
+    config.max_mint_amount = MAX_SUPPLY;
+    client.set_config(&owner, &config);
+
+    let other_minter = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("minter"), &other_minter, &None);
+    client.configure_minter(&owner, &other_minter, &100_0000000);
+
+    client.reserve_mint(&owner, &String::from_str(&env, "BIG"), &recipient, &MAX_SUPPLY, &100);
+
+    let small = String::from_str(&env, "SMALL");
+    assert_eq!(
+        client.try_admin_mint(&other_minter, &recipient, &1_0000000, &small),
+        Err(Ok(BOBTError::AmountTooLarge))
+    );
+}
+
+// =============================================================================
+// CHECKPOINT TESTS
+// =============================================================================
+
+#[test]
+fn test_balance_and_supply_at_past_ledgers() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(10);
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "REQ-1"));
+    env.ledger().set_sequence_number(20);
+    client.transfer(&alice, &bob, &30_0000000);
+    env.ledger().set_sequence_number(25);
+    client.admin_burn(&owner, &bob, &10_0000000, &String::from_str(&env, "BRN-1"));
+    env.ledger().set_sequence_number(30);
+
+    assert_eq!(client.balance_at(&alice, &5), Some(0));
+    assert_eq!(client.balance_at(&alice, &10), Some(100_0000000));
+    assert_eq!(client.balance_at(&alice, &19), Some(100_0000000));
+    assert_eq!(client.balance_at(&alice, &20), Some(70_0000000));
+    assert_eq!(client.balance_at(&bob, &15), Some(0));
+    assert_eq!(client.balance_at(&bob, &24), Some(30_0000000));
+    assert_eq!(client.balance_at(&bob, &30), Some(20_0000000));
+
+    assert_eq!(client.total_supply_at(&9), Some(0));
+    assert_eq!(client.total_supply_at(&20), Some(100_0000000));
+    assert_eq!(client.total_supply_at(&25), Some(90_0000000));
+
+    // Unknown accounts held nothing; the future is not answered
+    assert_eq!(client.balance_at(&Address::generate(&env), &15), Some(0));
+    assert_eq!(client.balance_at(&alice, &31), None);
+    assert_eq!(client.total_supply_at(&31), None);
+}
+
+#[test]
+fn test_same_ledger_changes_share_a_checkpoint() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(10);
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "REQ-1"));
+    client.transfer(&alice, &bob, &10_0000000);
+    client.transfer(&alice, &bob, &10_0000000);
+    client.admin_mint(&owner, &bob, &50_0000000, &String::from_str(&env, "REQ-2"));
+
+    assert_eq!(client.balance_at(&alice, &10), Some(80_0000000));
+    assert_eq!(client.total_supply_at(&10), Some(150_0000000));
+    let (checkpoints, supply_checkpoints) = env.as_contract(&client.address, || {
+        let checkpoints: Vec<Checkpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BalCkpt(alice.clone()))
+            .unwrap();
+        let supply_checkpoints: u32 = env.storage().instance().get(&KEY_SUPPLY_CKPT_COUNT).unwrap();
+        (checkpoints, supply_checkpoints)
+    });
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(supply_checkpoints, 2);
+}
+
+#[test]
+fn test_checkpoint_retention_drops_oldest() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    env.ledger().set_sequence_number(10);
+    client.admin_mint(&owner, &alice, &100_0000000, &String::from_str(&env, "REQ-1"));
+    for i in 0..MAX_BALANCE_CHECKPOINTS {
+        env.ledger().set_sequence_number(100 + i);
+        client.transfer(&alice, &bob, &1_0000000);
+    }
+
+    // The opening (0, 0) entry and the mint at ledger 10 were dropped
+    assert_eq!(client.balance_at(&alice, &5), None);
+    assert_eq!(client.balance_at(&alice, &50), None);
+    assert_eq!(client.balance_at(&alice, &100), Some(99_0000000));
+    assert_eq!(client.balance_at(&alice, &(99 + MAX_BALANCE_CHECKPOINTS)), Some(70_0000000));
+}
+
+#[test]
+fn test_supply_checkpoint_ring_buffer_drops_oldest() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+
+    env.ledger().set_sequence_number(10);
+    client.admin_mint(&owner, &alice, &1_0000000, &String::from_str(&env, "REQ-OPEN"));
+    for i in 0..MAX_SUPPLY_CHECKPOINTS {
+        env.ledger().set_sequence_number(100 + i);
+        let request_id = String::from_bytes(&env, &[b'A' + (i / 26) as u8, b'A' + (i % 26) as u8]);
+        client.admin_mint(&owner, &alice, &1_0000000, &request_id);
+    }
+
+    // The opening (0, 0) entry and the mint at ledger 10 were overwritten
+    assert_eq!(client.total_supply_at(&5), None);
+    assert_eq!(client.total_supply_at(&50), None);
+    assert_eq!(client.total_supply_at(&100), Some(2_0000000));
+    assert_eq!(client.total_supply_at(&150), Some(52_0000000));
+    assert_eq!(
+        client.total_supply_at(&(99 + MAX_SUPPLY_CHECKPOINTS)),
+        Some(i128::from(MAX_SUPPLY_CHECKPOINTS + 1) * 1_0000000)
+    );
+}
+
+// =============================================================================
+// PROOF OF RESERVES TESTS
+// =============================================================================
+
+const RESERVE_MAX_AGE: u64 = 86400;
+const NOW: u64 = 1_700_000_000;
+
+/// Minter setup with an attestor and the reserve gate enabled
+fn setup_with_attestor() -> (Env, Address, Address, BOBTTokenClient<'static>) {
+    let (env, owner, client) = setup_with_minter();
+    let attestor = Address::generate(&env);
+    env.ledger().set_timestamp(NOW);
+    client.grant_role(&owner, &Symbol::new(&env, "attestor"), &attestor, &None);
+    let config = ReserveConfig { max_age: RESERVE_MAX_AGE };
+    client.set_reserve_config(&owner, &Some(config));
+    (env, owner, attestor, client)
+}
+
+#[test]
+fn test_reserve_gate_caps_mints_at_attested_reserves() {
+    let (env, owner, attestor, client) = setup_with_attestor();
+    let user = Address::generate(&env);
+    let report = BytesN::from_array(&env, &[1; 32]);
+
+    assert_eq!(
+        client.try_admin_mint(&owner, &user, &100_0000000, &String::from_str(&env, "REQ-0")),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+
+    client.post_reserve_attestation(&attestor, &1000_0000000, &NOW, &report);
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "reserve_attested"), attestor.clone()).into_val(&env),
+                (1000_0000000i128, NOW, report.clone()).into_val(&env),
+            ),
+        ]
+    );
+
+    client.admin_mint(&owner, &user, &600_0000000, &String::from_str(&env, "REQ-1"));
+    assert_eq!(client.collateralization_ratio(), Some(16666));
+    assert_eq!(
+        client.try_admin_mint(&owner, &user, &500_0000000, &String::from_str(&env, "REQ-2")),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+
+    // Exactly fully backed is allowed
+    client.admin_mint(&owner, &user, &400_0000000, &String::from_str(&env, "REQ-3"));
+    assert_eq!(client.total_supply(), 1000_0000000);
+    assert_eq!(client.collateralization_ratio(), Some(10000));
+
+    // Burns are never gated
+    client.burn(&user, &100_0000000);
+    assert_eq!(client.collateralization_ratio(), Some(11111));
+}
+
+#[test]
+fn test_reserve_gate_counts_reservations() {
+    let (env, owner, attestor, client) = setup_with_attestor();
+    let user = Address::generate(&env);
+    let report = BytesN::from_array(&env, &[1; 32]);
+    let first = String::from_str(&env, "BNB-DEP-013");
+    let second = String::from_str(&env, "BNB-DEP-014");
+
+    assert_eq!(
+        client.try_reserve_mint(&owner, &first, &user, &600_0000000, &100),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+
+    client.post_reserve_attestation(&attestor, &1000_0000000, &NOW, &report);
+    client.reserve_mint(&owner, &first, &user, &600_0000000, &100);
+    assert_eq!(
+        client.try_reserve_mint(&owner, &second, &user, &500_0000000, &100),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+    assert_eq!(
+        client.try_admin_mint(&owner, &user, &500_0000000, &String::from_str(&env, "REQ-1")),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+
+    // Confirming does not count the reservation twice
+    client.reserve_mint(&owner, &second, &user, &400_0000000, &100);
+    client.confirm_mint(&owner, &first);
+    client.confirm_mint(&owner, &second);
+    assert_eq!(client.total_supply(), 1000_0000000);
+}
+
+#[test]
+fn test_stale_attestation_blocks_mints() {
+    let (env, owner, attestor, client) = setup_with_attestor();
+    let user = Address::generate(&env);
+    let report = BytesN::from_array(&env, &[1; 32]);
+
+    client.post_reserve_attestation(&attestor, &1000_0000000, &NOW, &report);
+    client.admin_mint(&owner, &user, &100_0000000, &String::from_str(&env, "REQ-1"));
+
+    env.ledger().set_timestamp(NOW + RESERVE_MAX_AGE + 1);
+    assert_eq!(
+        client.try_admin_mint(&owner, &user, &100_0000000, &String::from_str(&env, "REQ-2")),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+    // The ratio still reports the last attestation
+    assert_eq!(client.collateralization_ratio(), Some(100000));
+
+    let report = BytesN::from_array(&env, &[2; 32]);
+    client.post_reserve_attestation(&attestor, &1000_0000000, &(NOW + RESERVE_MAX_AGE), &report);
+    client.admin_mint(&owner, &user, &100_0000000, &String::from_str(&env, "REQ-2"));
+    assert_eq!(client.balance(&user), 200_0000000);
+}
+
+#[test]
+fn test_post_reserve_attestation_validation() {
+    let (env, owner, attestor, client) = setup_with_attestor();
+    let report = BytesN::from_array(&env, &[1; 32]);
+    let too_old = NOW - RESERVE_MAX_AGE - 1;
+
+    assert_eq!(
+        client.try_post_reserve_attestation(&owner, &1000_0000000, &NOW, &report),
+        Err(Ok(BOBTError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_post_reserve_attestation(&attestor, &-1, &NOW, &report),
+        Err(Ok(BOBTError::NegativeAmount))
+    );
+    assert_eq!(
+        client.try_post_reserve_attestation(&attestor, &1000_0000000, &(NOW + 1), &report),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+    assert_eq!(
+        client.try_post_reserve_attestation(&attestor, &1000_0000000, &too_old, &report),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+
+    client.post_reserve_attestation(&attestor, &1000_0000000, &(NOW - 60), &report);
+    let attestation = client.reserve_attestation().unwrap();
+    assert_eq!(attestation.reserves, 1000_0000000);
+    assert_eq!(attestation.as_of, NOW - 60);
+    assert_eq!(attestation.report_hash, report);
+    assert_eq!(attestation.attestor, attestor);
+
+    // An older or same-dated report cannot replace the latest one
+    assert_eq!(
+        client.try_post_reserve_attestation(&attestor, &5000_0000000, &(NOW - 60), &report),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+    assert_eq!(
+        client.try_post_reserve_attestation(&attestor, &5000_0000000, &(NOW - 120), &report),
+        Err(Ok(BOBTError::ReservesInsufficient))
+    );
+}
+
+#[test]
+fn test_reserve_gate_config_and_disable() {
+    let (env, owner, attestor, client) = setup_with_attestor();
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_reserve_config(&owner, &Some(ReserveConfig { max_age: 0 })),
+        Err(Ok(BOBTError::InvalidConfig))
+    );
+    assert_eq!(client.collateralization_ratio(), None);
+
+    client.post_reserve_attestation(&attestor, &0, &NOW, &BytesN::from_array(&env, &[1; 32]));
+    // No supply yet, so no ratio
+    assert_eq!(client.collateralization_ratio(), None);
+
+    client.set_reserve_config(&owner, &None);
+    assert_eq!(client.reserve_config(), None);
+    client.admin_mint(&owner, &user, &100_0000000, &String::from_str(&env, "REQ-1"));
+    assert_eq!(client.collateralization_ratio(), Some(0));
+}
+
+// =============================================================================
+// SEP-41 EVENT CONFORMANCE TESTS
+// =============================================================================
+
+#[test]
+fn test_event_admin_mint() {
+    let (env, owner, client) = setup_with_minter();
+    let to = Address::generate(&env);
+    let request_id = String::from_str(&env, "EVT_MINT");
+
+    client.admin_mint(&owner, &to, &250_0000000, &request_id);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint"), owner.clone(), to.clone()).into_val(&env),
+                250_0000000_i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "mint_request"), to.clone(), owner.clone()).into_val(&env),
+                (250_0000000_i128, request_id.clone()).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_transfer() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "EVT_TR"));
+
+    client.transfer(&alice, &bob, &100_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "transfer"), alice.clone(), bob.clone()).into_val(&env),
+                100_0000000_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_approve() {
+    let (env, _owner, client) = setup();
+    let alice = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.approve(&alice, &spender, &500_0000000, &1000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "approve"), alice.clone(), spender.clone()).into_val(&env),
+                (500_0000000_i128, 1000_u32).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_transfer_from() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "EVT_TF"));
+    client.approve(&alice, &spender, &500_0000000, &1000);
+
+    client.transfer_from(&spender, &alice, &bob, &200_0000000);
+
+    // The transfer event names the token owner, not the spender
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "transfer"), alice.clone(), bob.clone()).into_val(&env),
+                200_0000000_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_burn() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "EVT_BURN"));
+
+    client.burn(&alice, &400_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "burn"), alice.clone()).into_val(&env),
+                400_0000000_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_burn_from() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    let spender = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "EVT_BF"));
+    client.approve(&alice, &spender, &500_0000000, &1000);
+
+    client.burn_from(&spender, &alice, &300_0000000);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "burn"), alice.clone()).into_val(&env),
+                300_0000000_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_admin_burn() {
+    let (env, owner, client) = setup_with_minter();
+    let alice = Address::generate(&env);
+    client.admin_mint(&owner, &alice, &1000_0000000, &String::from_str(&env, "EVT_AB_M"));
+    let burn_req = String::from_str(&env, "EVT_AB_B");
+
+    client.admin_burn(&owner, &alice, &300_0000000, &burn_req);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "burn"), alice.clone()).into_val(&env),
+                300_0000000_i128.into_val(&env),
+            ),
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "burn_request"), alice.clone(), owner.clone()).into_val(&env),
+                (300_0000000_i128, burn_req.clone()).into_val(&env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_event_destroy_frozen_funds() {
+    let (env, owner, client) = setup_with_minter();
+    let bad_actor = Address::generate(&env);
+    client.grant_role(&owner, &symbol_short!("blklst"), &owner, &None);
+    client.admin_mint(&owner, &bad_actor, &500_0000000, &String::from_str(&env, "EVT_CB"));
+    freeze(&env, &client, &owner, &bad_actor);
+
+    client.destroy_frozen_funds(&owner, &bad_actor);
+
+    assert_eq!(
+        env.events().all(),
+        vec![
+            &env,
+            (
+                client.address.clone(),
+                (Symbol::new(&env, "clawback"), owner.clone(), bad_actor.clone()).into_val(&env),
+                500_0000000_i128.into_val(&env),
+            ),
+        ]
+    );
+}
+
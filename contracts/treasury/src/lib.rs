@@ -712,7 +712,13 @@ impl Treasury {
             }
             ProposalType::FreezeAccount => {
                 let token = BOBTTokenClient::new(&env, &config.token_address);
-                token.freeze(&env.current_contract_address(), &proposal.target);
+                token.freeze(
+                    &env.current_contract_address(),
+                    &proposal.target,
+                    &token_client::FreezeReason::Other,
+                    &proposal.external_ref,
+                    &None,
+                );
             }
             ProposalType::UnfreezeAccount => {
                 let token = BOBTTokenClient::new(&env, &config.token_address);
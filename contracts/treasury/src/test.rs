@@ -30,6 +30,9 @@ mod oracle_client {
 use token_client::Client as BOBTTokenClient;
 use oracle_client::Client as OracleClient;
 
+/// Mint allowance granted to the treasury on the token: 1 billion BOBT
+const TREASURY_MINT_ALLOWANCE: i128 = 1_000_000_000 * 10_000_000;
+
 // =============================================================================
 // TEST HELPERS
 // =============================================================================
@@ -84,13 +87,17 @@ fn setup_with_minter_role() -> (Env, Address, Address, Address, Address) {
     token_client.initialize(&token_owner);
 
     // Grant MINTER role to treasury
-    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("minter"), &treasury_id);
+    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("minter"), &treasury_id, &None);
+
+    // Give treasury a mint allowance
+    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("mstrmint"), &token_owner, &None);
+    token_client.configure_minter(&token_owner, &treasury_id, &TREASURY_MINT_ALLOWANCE);
 
     // Grant PAUSER role to treasury
-    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("pauser"), &treasury_id);
+    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("pauser"), &treasury_id, &None);
 
     // Grant BLACKLISTER role to treasury
-    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("blklst"), &treasury_id);
+    token_client.grant_role(&token_owner, &soroban_sdk::symbol_short!("blklst"), &treasury_id, &None);
 
     // Initialize treasury with 2-of-2 multi-sig
     let signers = vec![&env, signer1.clone(), signer2.clone()];
@@ -478,7 +485,11 @@ fn setup_with_oracle() -> (Env, Address, Address, Address, Address, Address) {
     token_client.initialize(&token_owner);
 
     // Grant MINTER role to treasury
-    token_client.grant_role(&token_owner, &symbol_short!("minter"), &treasury_id);
+    token_client.grant_role(&token_owner, &symbol_short!("minter"), &treasury_id, &None);
+
+    // Give treasury a mint allowance
+    token_client.grant_role(&token_owner, &symbol_short!("mstrmint"), &token_owner, &None);
+    token_client.configure_minter(&token_owner, &treasury_id, &TREASURY_MINT_ALLOWANCE);
 
     // Initialize Oracle with operator
     let operators = vec![&env, oracle_operator.clone()];
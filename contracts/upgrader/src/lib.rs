@@ -6,7 +6,10 @@
 
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, IntoVal, Val, Vec};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short, Address, BytesN, Env, Error,
+    IntoVal, InvokeError, Val, Vec,
+};
 
 #[contract]
 pub struct Upgrader;
@@ -20,12 +23,17 @@ impl Upgrader {
     /// 2. Immediately calls the `migrate` function to apply any necessary migrations
     ///
     /// Both operations happen in the same transaction, ensuring atomicity.
+    /// A migration error reverts the upgrade, except the contract error code
+    /// `skip_error`: an upgrade that keeps the storage version (e.g. a bug
+    /// fix) has nothing to migrate, so pass the target's "already migrated"
+    /// code (77 for the BOBT token) to accept it.
     ///
     /// # Arguments
     /// * `contract_address` - The address of the contract to upgrade
     /// * `operator` - The address of the authorized operator
     /// * `wasm_hash` - The hash of the new WASM binary
     /// * `migration_data` - Data to pass to the migrate function
+    /// * `skip_error` - Contract error code from `migrate` that is not an error
     ///
     /// # Authorization
     /// Requires authorization from the `operator`
@@ -35,6 +43,7 @@ impl Upgrader {
         operator: Address,
         wasm_hash: BytesN<32>,
         migration_data: Vec<Val>,
+        skip_error: Option<u32>,
     ) {
         operator.require_auth();
 
@@ -46,7 +55,19 @@ impl Upgrader {
         env.invoke_contract::<()>(&contract_address, &symbol_short!("upgrade"), upgrade_args);
 
         // Call migrate on the target contract
-        env.invoke_contract::<()>(&contract_address, &symbol_short!("migrate"), migration_data);
+        let migrated = env.try_invoke_contract::<(), InvokeError>(
+            &contract_address,
+            &symbol_short!("migrate"),
+            migration_data,
+        );
+        match migrated {
+            Ok(_) => {}
+            Err(Ok(InvokeError::Contract(code))) if Some(code) == skip_error => {}
+            Err(Ok(InvokeError::Contract(code))) => {
+                panic_with_error!(&env, Error::from_contract_error(code))
+            }
+            Err(_) => panic!("migrate aborted"),
+        }
     }
 
     /// Simple upgrade without migration
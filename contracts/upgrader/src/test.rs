@@ -1,7 +1,45 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+#[allow(clippy::too_many_arguments)]
+mod token_client {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32v1-none/release/bobt_token.wasm"
+    );
+}
+
+use token_client::Client as BOBTTokenClient;
+
+/// Deploy the BOBT token and schedule an upgrade to the same WASM, with the
+/// timelock already elapsed
+fn setup_scheduled_upgrade() -> (Env, Address, BOBTTokenClient<'static>, BytesN<32>) {
+    let env = Env::default();
+    env.mock_all_auths();
+    // Instantiating the token WASM from the upgrader exceeds the default budget
+    env.cost_estimate().budget().reset_unlimited();
+
+    let owner = Address::generate(&env);
+    let token_id = env.register(token_client::WASM, ());
+    let token = BOBTTokenClient::new(&env, &token_id);
+    token.initialize(&owner);
+
+    let wasm_hash = env.deployer().upload_contract_wasm(token_client::WASM);
+    token.schedule_upgrade(&owner, &wasm_hash);
+    let eta = env.ledger().sequence() + token.upgrade_delay();
+    env.ledger().set_sequence_number(eta);
+
+    (env, owner, token, wasm_hash)
+}
+
+/// Arguments for the token's `migrate()` (it takes none)
+fn migration_data(env: &Env) -> Vec<Val> {
+    Vec::new(env)
+}
 
 #[test]
 fn test_upgrader_deploys() {
@@ -27,3 +65,55 @@ fn test_upgrade_only_requires_auth() {
     // This test just verifies the upgrader contract exists
     let _operator = Address::generate(&env);
 }
+
+#[test]
+fn test_upgrade_and_migrate_same_version() {
+    let (env, owner, token, wasm_hash) = setup_scheduled_upgrade();
+    let upgrader = UpgraderClient::new(&env, &env.register(Upgrader, ()));
+    let version = token.version();
+
+    // The token's AlreadyMigrated (77) is expected for a same-version upgrade
+    let data = migration_data(&env);
+    assert_eq!(
+        upgrader.try_upgrade_and_migrate(&token.address, &owner, &wasm_hash, &data, &None),
+        Err(Ok(Error::from_contract_error(77)))
+    );
+    upgrader.upgrade_and_migrate(&token.address, &owner, &wasm_hash, &data, &Some(77));
+
+    assert_eq!(token.pending_upgrade(), None);
+    assert_eq!(token.version(), version);
+}
+
+#[test]
+fn test_upgrade_and_migrate_runs_pending_steps() {
+    let (env, owner, token, wasm_hash) = setup_scheduled_upgrade();
+    let upgrader = UpgraderClient::new(&env, &env.register(Upgrader, ()));
+    let version = token.version();
+    env.as_contract(&token.address, || {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("VERSION"), &(version - 1));
+    });
+
+    let data = migration_data(&env);
+    upgrader.upgrade_and_migrate(&token.address, &owner, &wasm_hash, &data, &Some(77));
+
+    assert_eq!(token.version(), version);
+}
+
+#[test]
+fn test_upgrade_and_migrate_reverts_on_migration_error() {
+    let (env, owner, token, wasm_hash) = setup_scheduled_upgrade();
+    let upgrader = UpgraderClient::new(&env, &env.register(Upgrader, ()));
+    env.as_contract(&token.address, || {
+        env.storage().instance().set(&symbol_short!("VERSION"), &99u32);
+    });
+
+    let data = migration_data(&env);
+    let result =
+        upgrader.try_upgrade_and_migrate(&token.address, &owner, &wasm_hash, &data, &Some(77));
+
+    // MigrationFailed (71) is passed through and the upgrade is rolled back
+    assert_eq!(result, Err(Ok(Error::from_contract_error(71))));
+    assert!(token.pending_upgrade().is_some());
+}
@@ -24,6 +24,8 @@ print_info() { echo -e "${CYAN}[INFO]${NC} $1"; }
 # Configuration
 NETWORK="testnet"
 SOURCE_ACCOUNT="${SOURCE_ACCOUNT:-bobt_owner}"
+# Owner mint allowance in stroops (default: 10 million BOBT)
+MINT_ALLOWANCE="${MINT_ALLOWANCE:-100000000000000}"
 
 cd "$(dirname "$0")/.."
 
@@ -100,6 +102,30 @@ stellar contract invoke \
     --account "$OWNER_ADDRESS"
 print_status "MINTER role granted"
 
+# Grant MASTER MINTER role
+stellar contract invoke \
+    --id "$CONTRACT_ID" \
+    --source-account "$SOURCE_ACCOUNT" \
+    --network "$NETWORK" \
+    -- \
+    grant_role \
+    --admin "$OWNER_ADDRESS" \
+    --role mstrmint \
+    --account "$OWNER_ADDRESS"
+print_status "MASTER MINTER role granted"
+
+# Configure owner mint allowance (minters cannot mint without one)
+stellar contract invoke \
+    --id "$CONTRACT_ID" \
+    --source-account "$SOURCE_ACCOUNT" \
+    --network "$NETWORK" \
+    -- \
+    configure_minter \
+    --master "$OWNER_ADDRESS" \
+    --minter "$OWNER_ADDRESS" \
+    --allowance "$MINT_ALLOWANCE"
+print_status "Mint allowance configured"
+
 # Grant PAUSER role
 stellar contract invoke \
     --id "$CONTRACT_ID" \